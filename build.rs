@@ -0,0 +1,36 @@
+//! Define the `cfg` aliases used in `src/lib.rs`
+
+use std::env;
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap_or_default();
+
+    // `prctl(PR_SET_PDEATHSIG)`, like `rustix`'s alias of the same name
+    let linux_kernel = os == "linux" || os == "android";
+    alias("linux_kernel", linux_kernel);
+    // the parent-death signal is supported at all
+    alias("pdeathsig", linux_kernel || os == "freebsd");
+    // like `rustix`'s alias of the same name
+    let bsd =
+        matches!(os.as_str(), "freebsd" | "dragonfly" | "netbsd" | "openbsd") || vendor == "apple";
+    alias("bsd", bsd);
+
+    // MIPS and SPARC have SIGEMT instead of SIGSTKFLT, and MIPS has 127 signals
+    let mips = matches!(arch.as_str(), "mips" | "mips32r6" | "mips64" | "mips64r6");
+    let sparc = matches!(arch.as_str(), "sparc" | "sparc64");
+    alias("linux_mips", linux_kernel && mips);
+    alias("has_sigstkflt", linux_kernel && !mips && !sparc);
+    alias("has_sigemt", bsd || (linux_kernel && (mips || sparc)));
+}
+
+/// Declare `#[cfg(name)]`, and enable it if `enabled` is true
+fn alias(name: &str, enabled: bool) {
+    println!("cargo::rustc-check-cfg=cfg({name})");
+    if enabled {
+        println!("cargo::rustc-cfg={name}");
+    }
+}
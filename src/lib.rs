@@ -2,31 +2,153 @@
 
 #![cfg_attr(docsrs, feature(auto_doc_cfg, doc_cfg))]
 
-use std::sync::OnceLock;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::mem;
+#[cfg(unix)]
+use std::os::fd::{AsRawFd, IntoRawFd, OwnedFd};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::thread::{self, ThreadId};
 
-use arrayvec::ArrayVec;
 use either::Either;
-use pyo3::exceptions::{PyOSError, PyValueError};
+#[cfg(unix)]
+use pyo3::exceptions::PyPermissionError;
+use pyo3::exceptions::{
+    PyIndexError, PyLookupError, PyNotImplementedError, PyOSError, PyProcessLookupError,
+    PyRuntimeError, PyRuntimeWarning, PyTypeError, PyValueError,
+};
 use pyo3::prelude::*;
-use rustix::process::{parent_process_death_signal, set_parent_process_death_signal, Signal};
+use pyo3::pyclass::CompareOp;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyBool, PyBytes, PyIterator, PyList, PyString, PyTuple, PyType};
+use pyo3::{PyErrArguments, intern};
+use rustix::io::Errno;
+#[cfg(unix)]
+use rustix::process::{Pid, Signal, WaitOptions, waitpid};
+#[cfg(target_os = "linux")]
+use rustix::process::{PidfdFlags, pidfd_open};
+#[cfg(pdeathsig)]
+use rustix::process::{getpid, kill_process};
+#[cfg(pdeathsig)]
+use rustix::process::{parent_process_death_signal, set_parent_process_death_signal};
+#[cfg(linux_kernel)]
+use rustix::thread::gettid;
 
 /// A Python module implemented in Rust.
 #[pymodule(name = "_pdeathsignal")]
 fn pdeathsignal(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<WrappedSignal>()?;
+    m.add_class::<SignalSet>()?;
+    m.add_class::<Policy>()?;
+    m.add_class::<DisabledType>()?;
     m.add_function(wrap_pyfunction!(get, m)?)?;
+    m.add_function(wrap_pyfunction!(get_raw, m)?)?;
+    m.add_function(wrap_pyfunction!(is_set, m)?)?;
     m.add_function(wrap_pyfunction!(set, m)?)?;
+    m.add_function(wrap_pyfunction!(try_set, m)?)?;
+    m.add_function(wrap_pyfunction!(set_first_supported, m)?)?;
+    m.add_function(wrap_pyfunction!(set_from_env, m)?)?;
+    m.add_function(wrap_pyfunction!(set_and_get, m)?)?;
+    m.add_function(wrap_pyfunction!(clear, m)?)?;
+    m.add_function(wrap_pyfunction!(reset, m)?)?;
+    m.add_function(wrap_pyfunction!(can_set, m)?)?;
+    m.add_function(wrap_pyfunction!(signals, m)?)?;
+    m.add_function(wrap_pyfunction!(recommended_signals, m)?)?;
+    m.add_function(wrap_pyfunction!(blocked_signals, m)?)?;
+    m.add_function(wrap_pyfunction!(ensure_deliverable, m)?)?;
+    m.add_function(wrap_pyfunction!(set_trace_hook, m)?)?;
+    m.add_function(wrap_pyfunction!(self_test, m)?)?;
+    m.add_function(wrap_pyfunction!(supported, m)?)?;
+    m.add_function(wrap_pyfunction!(getppid, m)?)?;
+    m.add_function(wrap_pyfunction!(owning_tid, m)?)?;
+    m.add_function(wrap_pyfunction!(getsid, m)?)?;
+    m.add_function(wrap_pyfunction!(getpgid, m)?)?;
+    m.add_function(wrap_pyfunction!(parent_is_alive, m)?)?;
+    m.add_function(wrap_pyfunction!(set_if_orphaned, m)?)?;
+    m.add_function(wrap_pyfunction!(raise_configured, m)?)?;
+    m.add_function(wrap_pyfunction!(wait_parent_exit, m)?)?;
+    m.add_function(wrap_pyfunction!(wait_parent_exit_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(parent_death_fd, m)?)?;
+    m.add_function(wrap_pyfunction!(scoped, m)?)?;
+    m.add_function(wrap_pyfunction!(suspended, m)?)?;
+    m.add_function(wrap_pyfunction!(guard, m)?)?;
+    m.add_function(wrap_pyfunction!(on_parent_death, m)?)?;
+    m.add_function(wrap_pyfunction!(keep_across_fork, m)?)?;
+
+    // `help(Signal.SIGTERM)` should describe the signal, not the class
+    let signal_type = m.py().get_type_bound::<WrappedSignal>();
+    let class_doc = signal_type.getattr(intern!(m.py(), "__doc__"))?.unbind();
+    signal_type.setattr(
+        intern!(m.py(), "__doc__"),
+        Py::new(m.py(), SignalDoc { class_doc })?,
+    )?;
+
+    // like `signal.SIGTERM`, the signals are available as module attributes, too
+    for signal in WrappedSignal::all(m.py())? {
+        m.add(signal.get().name().as_ref(), signal)?;
+    }
+    for &(alias, signal) in SIGNAL_ALIASES {
+        m.add(alias, WrappedSignal::from_signal(m.py(), signal)?)?;
+    }
+    #[cfg(linux_kernel)]
+    {
+        m.add("SIGRTMIN", WrappedSignal::sigrtmin(m.py())?)?;
+        m.add("SIGRTMAX", WrappedSignal::sigrtmax(m.py())?)?;
+
+        // for users who want to call `prctl()` themselves
+        m.add("PR_SET_PDEATHSIG", libc::PR_SET_PDEATHSIG)?;
+        m.add("PR_GET_PDEATHSIG", libc::PR_GET_PDEATHSIG)?;
+    }
+    m.add("NSIG", nsig())?;
+    m.add("DISABLED", DisabledType::get(m.py())?)?;
+
+    // the kernel clears the parent-death signal of a forked child, so `set(strict=True)` should
+    // not complain there
+    #[cfg(unix)]
+    {
+        let hook = wrap_pyfunction!(forget_signal_after_fork, m)?;
+        let kwargs = [("after_in_child", hook)].into_py_dict_bound(m.py());
+        let _ = m
+            .py()
+            .import_bound("os")?
+            .call_method("register_at_fork", (), Some(&kwargs))?;
+    }
+
+    // `add()` maintains `__all__`, which should not contain the version
+    m.setattr("__version__", env!("CARGO_PKG_VERSION"))?;
+    // nor the handles returned by e.g. `scoped()`, which are only available for `isinstance()`
+    m.setattr("Scoped", m.py().get_type_bound::<Scoped>())?;
+    m.setattr("Guard", m.py().get_type_bound::<Guard>())?;
+    m.setattr(
+        "ParentDeathHandler",
+        m.py().get_type_bound::<ParentDeathHandler>(),
+    )?;
+    m.setattr("ForkHook", m.py().get_type_bound::<ForkHook>())?;
     Ok(())
 }
 
 /// A signal number
-#[pyclass(frozen, freelist = 32)]
+///
+/// Like enum members, signals are singletons, so `Signal(15) is Signal.SIGTERM`.
+/// `signal.value`, `int(signal)` and `hash(signal)` are the signal number, like for `signal.Signals`.
+/// A signal does not compare equal to its mnemonic, because the hashes would differ.
+/// Use `Signal.from_name(name) == signal` instead.
+/// `Signal(0)` raises `ValueError`: [`set()`] treats 0 as "no signal", but 0 is no signal itself.
+/// `bool` is rejected with a `TypeError` everywhere, although it is a subclass of `int`.
+/// The class is final, because instances of a subclass would bypass the cache of singletons.
+// Without the `subclass` option, Python raises a `TypeError` when the class is subclassed.
+// The freelist is sized like the cache of singletons, so both follow `SIGNAL_COUNT`.
+#[pyclass(frozen, freelist = SIGNAL_COUNT, module = "pdeathsignal")]
 #[pyo3(name = "Signal")]
 #[derive(Debug, Clone, Copy)]
-struct WrappedSignal(Signal);
+struct WrappedSignal(i32);
 
 #[pymethods]
 impl WrappedSignal {
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGHUP")]
     fn sighup(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -39,6 +161,7 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Int)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGQUIT")]
     fn sigquit(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -51,6 +174,7 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Ill)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGTRAP")]
     fn sigtrap(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -63,6 +187,7 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Abort)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGBUS")]
     fn sigbus(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -75,12 +200,14 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Fpe)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGKILL")]
     fn sigkill(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Kill)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGUSR1")]
     fn sigusr1(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -93,18 +220,21 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Segv)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGUSR2")]
     fn sigusr2(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Usr2)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGPIPE")]
     fn sigpipe(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Pipe)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGALRM")]
     fn sigalrm(py: Python<'_>) -> PyResult<Py<Self>> {
@@ -117,277 +247,2768 @@ impl WrappedSignal {
         Self::from_signal(py, Signal::Term)
     }
 
+    #[cfg(has_sigstkflt)]
     #[classattr]
     #[pyo3(name = "SIGSTKFLT")]
     fn sigstkflt(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Stkflt)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGCHLD")]
     fn sigchld(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Child)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGCONT")]
     fn sigcont(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Cont)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGSTOP")]
     fn sigstop(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Stop)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGTSTP")]
     fn sigtstp(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Tstp)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGTTIN")]
     fn sigttin(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Ttin)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGTTOU")]
     fn sigttou(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Ttou)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGURG")]
     fn sigurg(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Urg)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGXCPU")]
     fn sigxcpu(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Xcpu)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGXFSZ")]
     fn sigxfsz(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Xfsz)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGVTALRM")]
     fn sigvtalrm(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Vtalarm)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGPROF")]
     fn sigprof(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Prof)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGWINCH")]
     fn sigwinch(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Winch)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGIO")]
     fn sigio(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Io)
     }
 
+    #[cfg(linux_kernel)]
     #[classattr]
     #[pyo3(name = "SIGPWR")]
     fn sigpwr(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Power)
     }
 
+    #[cfg(unix)]
     #[classattr]
     #[pyo3(name = "SIGSYS")]
     fn sigsys(py: Python<'_>) -> PyResult<Py<Self>> {
         Self::from_signal(py, Signal::Sys)
     }
 
-    fn __str__(&self) -> &'static str {
-        match self.0 {
-            Signal::Hup => "SIGHUP",
-            Signal::Int => "SIGINT",
-            Signal::Quit => "SIGQUIT",
-            Signal::Ill => "SIGILL",
-            Signal::Trap => "SIGTRAP",
-            Signal::Abort => "SIGABRT",
-            Signal::Bus => "SIGBUS",
-            Signal::Fpe => "SIGFPE",
-            Signal::Kill => "SIGKILL",
-            Signal::Usr1 => "SIGUSR1",
-            Signal::Segv => "SIGSEGV",
-            Signal::Usr2 => "SIGUSR2",
-            Signal::Pipe => "SIGPIPE",
-            Signal::Alarm => "SIGALRM",
-            Signal::Term => "SIGTERM",
-            Signal::Stkflt => "SIGSTKFLT",
-            Signal::Child => "SIGCHLD",
-            Signal::Cont => "SIGCONT",
-            Signal::Stop => "SIGSTOP",
-            Signal::Tstp => "SIGTSTP",
-            Signal::Ttin => "SIGTTIN",
-            Signal::Ttou => "SIGTTOU",
-            Signal::Urg => "SIGURG",
-            Signal::Xcpu => "SIGXCPU",
-            Signal::Xfsz => "SIGXFSZ",
-            Signal::Vtalarm => "SIGVTALRM",
-            Signal::Prof => "SIGPROF",
-            Signal::Winch => "SIGWINCH",
-            Signal::Io => "SIGIO",
-            Signal::Power => "SIGPWR",
-            Signal::Sys => "SIGSYS",
+    // historical aliases, c.f. `SIGNAL_ALIASES`
+
+    #[cfg(unix)]
+    #[classattr]
+    #[pyo3(name = "SIGIOT")]
+    fn sigiot(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Abort)
+    }
+
+    #[cfg(linux_kernel)]
+    #[classattr]
+    #[pyo3(name = "SIGCLD")]
+    fn sigcld(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Child)
+    }
+
+    #[cfg(linux_kernel)]
+    #[classattr]
+    #[pyo3(name = "SIGPOLL")]
+    fn sigpoll(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Io)
+    }
+
+    #[cfg(linux_kernel)]
+    #[classattr]
+    #[pyo3(name = "SIGUNUSED")]
+    fn sigunused(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Sys)
+    }
+
+    #[cfg(has_sigemt)]
+    #[classattr]
+    #[pyo3(name = "SIGEMT")]
+    fn sigemt(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Emt)
+    }
+
+    #[cfg(bsd)]
+    #[classattr]
+    #[pyo3(name = "SIGINFO")]
+    fn siginfo(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Info)
+    }
+
+    #[cfg(target_os = "freebsd")]
+    #[classattr]
+    #[pyo3(name = "SIGTHR")]
+    fn sigthr(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Thr)
+    }
+
+    #[cfg(target_os = "freebsd")]
+    #[classattr]
+    #[pyo3(name = "SIGLIBRT")]
+    fn siglibrt(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_signal(py, Signal::Librt)
+    }
+
+    #[cfg(linux_kernel)]
+    #[classattr]
+    #[pyo3(name = "SIGRTMIN")]
+    fn sigrtmin(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_number(py, libc::SIGRTMIN())
+    }
+
+    #[cfg(linux_kernel)]
+    #[classattr]
+    #[pyo3(name = "SIGRTMAX")]
+    fn sigrtmax(py: Python<'_>) -> PyResult<Py<Self>> {
+        Self::from_number(py, libc::SIGRTMAX())
+    }
+
+    /// The job-control signals: SIGCONT, SIGSTOP, SIGTSTP, SIGTTIN and SIGTTOU
+    #[cfg(unix)]
+    #[classattr]
+    #[pyo3(name = "JOB_CONTROL")]
+    fn job_control() -> SignalSet {
+        SignalSet::of(&[
+            Signal::Cont,
+            Signal::Stop,
+            Signal::Tstp,
+            Signal::Ttin,
+            Signal::Ttou,
+        ])
+    }
+
+    /// The signals sent by a terminal: SIGHUP, SIGINT, SIGQUIT and SIGWINCH
+    #[cfg(unix)]
+    #[classattr]
+    #[pyo3(name = "TERMINAL")]
+    fn terminal() -> SignalSet {
+        SignalSet::of(&[Signal::Hup, Signal::Int, Signal::Quit, Signal::Winch])
+    }
+
+    /// The signals that dump core by default, i.e. their `default_action` is `"core"`
+    #[classattr]
+    #[pyo3(name = "CORE_DUMPING")]
+    fn core_dumping() -> SignalSet {
+        SignalSet(
+            (1..=64)
+                .filter(|&signal| signal_from_raw(signal).is_ok())
+                .filter(|&signal| WrappedSignal(signal).default_action() == "core")
+                .fold(0, |mask, signal| mask | signal_bit(signal)),
+        )
+    }
+
+    /// The real-time signal `SIGRTMIN + offset`
+    #[cfg(linux_kernel)]
+    #[staticmethod]
+    fn rtmin_plus(offset: i32, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        match libc::SIGRTMIN().checked_add(offset) {
+            Some(signal) if offset >= 0 && signal <= libc::SIGRTMAX() => {
+                Self::from_number(py, signal)
+            },
+            _ => Err(PyValueError::new_err((format!(
+                "Illegal real-time signal offset {offset}"
+            ),))),
         }
     }
 
-    fn __repr__(&self) -> &'static str {
-        match self.0 {
-            Signal::Hup => "pdeathsignal.Signal.SIGHUP",
-            Signal::Int => "pdeathsignal.Signal.SIGINT",
-            Signal::Quit => "pdeathsignal.Signal.SIGQUIT",
-            Signal::Ill => "pdeathsignal.Signal.SIGILL",
-            Signal::Trap => "pdeathsignal.Signal.SIGTRAP",
-            Signal::Abort => "pdeathsignal.Signal.SIGABRT",
-            Signal::Bus => "pdeathsignal.Signal.SIGBUS",
-            Signal::Fpe => "pdeathsignal.Signal.SIGFPE",
-            Signal::Kill => "pdeathsignal.Signal.SIGKILL",
-            Signal::Usr1 => "pdeathsignal.Signal.SIGUSR1",
-            Signal::Segv => "pdeathsignal.Signal.SIGSEGV",
-            Signal::Usr2 => "pdeathsignal.Signal.SIGUSR2",
-            Signal::Pipe => "pdeathsignal.Signal.SIGPIPE",
-            Signal::Alarm => "pdeathsignal.Signal.SIGALRM",
-            Signal::Term => "pdeathsignal.Signal.SIGTERM",
-            Signal::Stkflt => "pdeathsignal.Signal.SIGSTKFLT",
-            Signal::Child => "pdeathsignal.Signal.SIGCHLD",
-            Signal::Cont => "pdeathsignal.Signal.SIGCONT",
-            Signal::Stop => "pdeathsignal.Signal.SIGSTOP",
-            Signal::Tstp => "pdeathsignal.Signal.SIGTSTP",
-            Signal::Ttin => "pdeathsignal.Signal.SIGTTIN",
-            Signal::Ttou => "pdeathsignal.Signal.SIGTTOU",
-            Signal::Urg => "pdeathsignal.Signal.SIGURG",
-            Signal::Xcpu => "pdeathsignal.Signal.SIGXCPU",
-            Signal::Xfsz => "pdeathsignal.Signal.SIGXFSZ",
-            Signal::Vtalarm => "pdeathsignal.Signal.SIGVTALRM",
-            Signal::Prof => "pdeathsignal.Signal.SIGPROF",
-            Signal::Winch => "pdeathsignal.Signal.SIGWINCH",
-            Signal::Io => "pdeathsignal.Signal.SIGIO",
-            Signal::Power => "pdeathsignal.Signal.SIGPWR",
-            Signal::Sys => "pdeathsignal.Signal.SIGSYS",
+    fn __str__(&self) -> Cow<'static, str> {
+        self.name()
+    }
+
+    /// The signal number as a plain `int`, same as `int(signal)`
+    #[getter]
+    fn raw(&self) -> i32 {
+        self.0
+    }
+
+    /// The signal number, like `signal.Signals.SIGTERM.value`
+    #[getter]
+    fn value(&self) -> i32 {
+        self.0
+    }
+
+    // allows `case Signal(15):` in a `match` statement
+    #[classattr]
+    fn __match_args__() -> (&'static str,) {
+        ("value",)
+    }
+
+    /// The mnemonic of the signal, e.g. `"SIGTERM"` or `"SIGRTMIN+3"`
+    #[getter]
+    fn name(&self) -> Cow<'static, str> {
+        match self.named() {
+            Some(name) => Cow::Borrowed(name),
+            None => Cow::Owned(realtime_name(self.0)),
+        }
+    }
+
+    /// The mnemonic of the signal without its `SIG` prefix, e.g. `"TERM"`
+    #[getter]
+    fn abbrev(&self) -> Cow<'static, str> {
+        match self.name() {
+            Cow::Borrowed(name) => Cow::Borrowed(name.strip_prefix("SIG").unwrap_or(name)),
+            Cow::Owned(name) => Cow::Owned(name.strip_prefix("SIG").unwrap_or(&name).to_owned()),
+        }
+    }
+
+    /// A human-readable description of the signal, e.g. `"Terminated"`
+    ///
+    /// The description is provided by `strsignal(3)`. The mnemonic is used as fallback.
+    #[getter]
+    fn description(&self, py: Python<'_>) -> PyResult<String> {
+        // `signal.strsignal()` calls `strsignal(3)` for us, so we don't need any `unsafe` code
+        let description = py
+            .import_bound("signal")?
+            .getattr("strsignal")?
+            .call1((self.0,))?;
+        if description.is_none() {
+            Ok(self.name().into_owned())
+        } else {
+            description.extract()
+        }
+    }
+
+    /// Whether the signal can be caught, blocked or ignored, i.e. it is neither SIGKILL nor SIGSTOP
+    #[getter]
+    fn is_catchable(&self) -> bool {
+        #[cfg(unix)]
+        {
+            !matches!(self.signal(), Some(Signal::Kill | Signal::Stop))
+        }
+        #[cfg(not(unix))]
+        true
+    }
+
+    /// Whether the signal is a job-control signal that stops the process
+    #[getter]
+    fn is_stop(&self) -> bool {
+        #[cfg(unix)]
+        {
+            matches!(
+                self.signal(),
+                Some(Signal::Stop | Signal::Tstp | Signal::Ttin | Signal::Ttou)
+            )
+        }
+        #[cfg(not(unix))]
+        false
+    }
+
+    /// The default action of the signal: `"term"`, `"core"`, `"ignore"`, `"stop"` or `"cont"`
+    #[getter]
+    fn default_action(&self) -> &'static str {
+        // the C runtime of e.g. Windows terminates the process for every signal
+        #[cfg(not(unix))]
+        return "term";
+        #[cfg(unix)]
+        match self.signal() {
+            Some(
+                Signal::Quit
+                | Signal::Ill
+                | Signal::Trap
+                | Signal::Abort
+                | Signal::Bus
+                | Signal::Fpe
+                | Signal::Segv
+                | Signal::Xcpu
+                | Signal::Xfsz
+                | Signal::Sys,
+            ) => "core",
+            Some(Signal::Child | Signal::Urg | Signal::Winch) => "ignore",
+            Some(Signal::Stop | Signal::Tstp | Signal::Ttin | Signal::Ttou) => "stop",
+            Some(Signal::Cont) => "cont",
+            #[cfg(has_sigemt)]
+            Some(Signal::Emt) => "core",
+            #[cfg(bsd)]
+            Some(Signal::Info | Signal::Io) => "ignore",
+            #[cfg(target_os = "freebsd")]
+            Some(Signal::Thr | Signal::Librt) => "ignore",
+            _ => "term",
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match self.named() {
+            Some(name) => format!("pdeathsignal.Signal.{name}"),
+            None if is_realtime_limit(self.0) => format!("pdeathsignal.Signal.{}", self.name()),
+            // e.g. `SIGRTMIN+3` is no attribute of `Signal`
+            None => format!("pdeathsignal.Signal({})", self.0),
         }
     }
 
+    /// The mnemonic of the signal, e.g. `"SIGTERM"`, for log lines where `repr()` is too verbose
+    fn compact_repr(&self) -> Cow<'static, str> {
+        self.name()
+    }
+
+    // A signal owns no memory besides the object itself.
+    fn __sizeof__(slf: &Bound<'_, Self>) -> PyResult<usize> {
+        slf.get_type()
+            .getattr(intern!(slf.py(), "__basicsize__"))?
+            .extract()
+    }
+
+    /// Format the number for numeric format specs like `"d"` or `"x"`, otherwise the mnemonic
+    fn __format__(&self, spec: &str, py: Python<'_>) -> PyResult<PyObject> {
+        let value = if spec.ends_with(['b', 'c', 'd', 'o', 'x', 'X', 'n']) {
+            self.0.into_py(py)
+        } else {
+            self.name().into_py(py)
+        };
+        value.call_method1(py, "__format__", (spec,))
+    }
+
     fn __index__(&self) -> i32 {
-        self.0 as i32
+        self.0
     }
 
     fn __int__(&self) -> i32 {
-        self.0 as i32
+        self.0
+    }
+
+    /// Always `True`: there is no signal 0, so every signal is truthy, and `if get():` is the same
+    /// as `if get() is not None:`
+    fn __bool__(&self) -> bool {
+        true
+    }
+
+    /// The signal number as a single byte
+    fn __bytes__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let byte = u8::try_from(self.0).map_err(|_| illegal_signal(self.0))?;
+        Ok(PyBytes::new_bound(py, &[byte]))
     }
 
     fn __pos__(&self) -> i32 {
-        self.0 as i32
+        self.0
+    }
+
+    /// `signal + n` is the signal `n` numbers after `signal`, e.g. `Signal.SIGRTMIN + 3`
+    ///
+    /// The result is a `Signal`, not an `int`, so a `ValueError` is raised if there is no such
+    /// signal. Adding two signals is a `TypeError`.
+    fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let py = other.py();
+        if other.is_instance_of::<WrappedSignal>() {
+            return Ok(py.NotImplemented());
+        }
+        match other.extract::<Int<i32>>() {
+            Ok(Int(offset)) => self.offset_by(py, offset.into()),
+            Err(_) => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.__add__(other)
+    }
+
+    /// `signal - n` is the signal `n` numbers before `signal`, and `signal - other` is the
+    /// distance between two signals as an `int`
+    fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let py = other.py();
+        if let Ok(other) = other.downcast::<WrappedSignal>() {
+            return Ok((self.0 - other.get().0).into_py(py));
+        }
+        match other.extract::<Int<i32>>() {
+            Ok(Int(offset)) => self.offset_by(py, -i64::from(offset)),
+            Err(_) => Ok(py.NotImplemented()),
+        }
     }
 
+    // Only meant for display, e.g. `kill -15`; use `raw` or `int(signal)` for arithmetic.
+    // Signal numbers are small and positive, even the real-time ones, so this cannot overflow.
     fn __neg__(&self) -> i32 {
-        -(self.0 as i32)
+        -(self.0)
     }
 
-    #[new]
-    fn __new__(
-        value: Either<Py<WrappedSignal>, i32>,
-        py: Python<'_>,
-    ) -> PyResult<Py<WrappedSignal>> {
-        let signal = match value {
-            Either::Left(value) => return Ok(value),
-            Either::Right(signal) => signal,
+    /// Signals are ordered by their number, so real-time signals sort after the classic ones
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyObject {
+        let py = other.py();
+        let other = match other.extract::<Either<WrappedSignal, i32>>() {
+            Ok(Either::Left(WrappedSignal(other)) | Either::Right(other)) => other,
+            Err(_) => return py.NotImplemented(),
         };
-        match Signal::from_raw(signal) {
-            Some(signal) => WrappedSignal::from_signal(py, signal),
-            None => Err(PyValueError::new_err((format!(
-                "Illegal signal number {signal}"
+        op.matches((self.0).cmp(&other)).into_py(py)
+    }
+
+    fn __hash__(&self) -> isize {
+        // same as `hash(int(self))`, so signals and numbers are interchangeable as dict keys
+        self.0 as isize
+    }
+
+    fn __copy__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __deepcopy__(slf: Py<Self>, _memo: &Bound<'_, PyAny>) -> Py<Self> {
+        slf
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (i32,)) {
+        (slf.get_type(), (slf.get().0,))
+    }
+
+    // signals are reconstructed by their number, independent of the pickle protocol
+    #[pyo3(signature = (_protocol, /))]
+    fn __reduce_ex__<'py>(slf: &Bound<'py, Self>, _protocol: i32) -> (Bound<'py, PyType>, (i32,)) {
+        Self::__reduce__(slf)
+    }
+
+    fn __getstate__(&self) -> i32 {
+        self.0
+    }
+
+    // Signals are immutable, so the state can only be checked, not applied. This is enough for
+    // serializers that call `__setstate__()` on the object that `__reduce__()` produced.
+    fn __setstate__(&self, state: i32) -> PyResult<()> {
+        match signal_from_raw(state)? {
+            signal if signal == self.0 => Ok(()),
+            signal => Err(PyValueError::new_err((format!(
+                "cannot change {} into {}",
+                self.name(),
+                WrappedSignal(signal).name(),
+            ),))),
+        }
+    }
+
+    // A `Signal` argument is looked up by its number, too, so `Signal(signal) is signal`.
+    #[new]
+    fn __new__(value: SignalArg, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        WrappedSignal::parse(value, py)
+    }
+
+    /// Convert a Signal, a signal number, a mnemonic, or a decimal string like `"15"` to a Signal
+    #[staticmethod]
+    fn parse(value: SignalArg, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        WrappedSignal::from_number(py, signal_from_raw(value.number()?)?)
+    }
+
+    /// Like `Signal(value)`, but only accept a Signal or an integer, not a mnemonic
+    ///
+    /// Unlike for [`set()`], where 0 means "disable", 0 is rejected with a dedicated message.
+    #[staticmethod]
+    fn strict(value: &Bound<'_, PyAny>, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        let is_number = value.is_instance_of::<WrappedSignal>()
+            || value.hasattr(intern!(py, "__index__"))? && !value.is_instance_of::<PyBool>();
+        if !is_number {
+            return Err(PyTypeError::new_err((format!(
+                "signal must be a Signal or int, not {}",
+                value.get_type().qualname()?,
+            ),)));
+        }
+        match value.extract()? {
+            SignalArg::Number(0) => Err(PyValueError::new_err((
+                "0 is no signal; use clear() to disable the parent-death signal",
+            ))),
+            value => WrappedSignal::parse(value, py),
+        }
+    }
+
+    /// `Signal[...]` is `Signal` itself, so that subscripted annotations don't fail at runtime
+    #[classmethod]
+    fn __class_getitem__<'py>(
+        cls: &Bound<'py, PyType>,
+        _item: &Bound<'py, PyAny>,
+    ) -> Bound<'py, PyType> {
+        cls.clone()
+    }
+
+    /// Look up a signal by its mnemonic, e.g. `"SIGTERM"` or `"term"`
+    #[staticmethod]
+    fn from_name(name: &str, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        match signal_from_name(name) {
+            Some(signal) => WrappedSignal::from_number(py, signal),
+            None => Err(unknown_signal_name(name)),
+        }
+    }
+
+    /// Look up a signal by a number that came from C, e.g. `ctypes.c_int(15).value`
+    ///
+    /// Like `Signal(int)`, but without checking for a Signal or a mnemonic first.
+    /// Raises `ValueError` if there is no such signal.
+    #[staticmethod]
+    #[pyo3(signature = (value, /))]
+    fn from_c_int(value: Int<i32>, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        WrappedSignal::from_number(py, signal_from_raw(value.0)?)
+    }
+
+    /// Look up a signal by its number, raising `IndexError` if there is no such signal
+    ///
+    /// Unlike `Signal(int)`, this only accepts plain integers, so it is a bit faster.
+    #[staticmethod]
+    fn from_index(index: Int<i64>, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        let Int(index) = index;
+        match i32::try_from(index) {
+            Ok(signal) if signal_from_raw(signal).is_ok() => WrappedSignal::from_number(py, signal),
+            _ => Err(PyIndexError::new_err((format!(
+                "signal index {index} out of range"
+            ),))),
+        }
+    }
+
+    /// The inverse of `bytes(signal)`: look up a signal by its number stored in a single byte
+    #[staticmethod]
+    fn from_bytes(data: &[u8], py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        match *data {
+            [signal] => WrappedSignal::from_number(py, signal_from_raw(signal.into())?),
+            _ => Err(PyValueError::new_err((format!(
+                "expected a single byte, got {}",
+                data.len(),
             ),))),
         }
     }
 
+    /// Convert a `signal.Signals` (or any integer) to a Signal
+    #[staticmethod]
+    fn from_stdlib(signal: Int<i32>, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        WrappedSignal::from_number(py, signal_from_raw(signal.0)?)
+    }
+
+    /// Convert the signal to the corresponding `signal.Signals` member
+    ///
+    /// Signals implement `__index__`, so they can be used directly with e.g. `os.kill()` or
+    /// `signal.signal()`. Use this method for APIs that check `isinstance(sig, signal.Signals)`.
+    #[allow(clippy::wrong_self_convention)] // pymethods cannot take `self` by value
+    fn to_stdlib<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        py.import_bound("signal")?
+            .getattr("Signals")?
+            .call1((self.0,))
+    }
+
+    /// The signals in a mask like `SigBlk` in `/proc/<pid>/status`, where signal `n` is bit `n - 1`
+    ///
+    /// Bits that are no valid signal are ignored, unless `strict` is true.
+    #[staticmethod]
+    #[pyo3(signature = (mask, /, *, strict = false))]
+    fn from_mask(mask: Int<u64>, strict: bool, py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+        let Int(mask) = mask;
+        let mut signals = Vec::with_capacity(mask.count_ones() as usize);
+        for signal in (1..=64).filter(|&signal| mask & signal_bit(signal) != 0) {
+            match signal_from_raw(signal) {
+                Ok(signal) => signals.push(WrappedSignal::from_number(py, signal)?),
+                Err(err) if strict => return Err(err),
+                Err(_) => {},
+            }
+        }
+        Ok(signals)
+    }
+
+    /// The inverse of [`from_mask()`](Self::from_mask)
+    #[staticmethod]
+    fn to_mask(signals: &Bound<'_, PyAny>) -> PyResult<u64> {
+        let mut mask = 0;
+        for signal in signals.iter()? {
+            let Int(signal) = signal?.extract()?;
+            mask |= checked_signal_bit(signal_from_raw(signal)?)?;
+        }
+        Ok(mask)
+    }
+
+    /// All known signals in ascending numeric order
+    #[staticmethod]
+    fn all(py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+        (1..SIGNAL_COUNT as i32)
+            .filter_map(Signal::from_raw)
+            .map(|signal| WrappedSignal::from_signal(py, signal))
+            .collect()
+    }
+
     #[staticmethod]
     fn get(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
         do_get(py)
     }
 
+    /// The currently configured parent-death signal, or `None`, same as [`get()`]
+    #[staticmethod]
+    fn current(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+        do_get(py)
+    }
+
     fn set(&self) -> PyResult<()> {
         do_set(Some(self.0))
     }
+
+    /// `with Signal.SIGTERM:` sets the parent-death signal for the duration of the block
+    ///
+    /// Like [`scoped()`], the previous setting is restored when the block is left.
+    fn __enter__(slf: Py<Self>, py: Python<'_>) -> PyResult<Py<Self>> {
+        let previous = do_get_raw(py)?;
+        do_set(Some(slf.get().0))?;
+        signal_scopes()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(thread::current().id())
+            .or_default()
+            .push(previous);
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&self, _args: &Bound<'_, PyTuple>) -> PyResult<bool> {
+        let previous = {
+            let mut scopes = signal_scopes()
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let Entry::Occupied(mut entry) = scopes.entry(thread::current().id()) else {
+                return Ok(false);
+            };
+            let previous = entry.get_mut().pop();
+            if entry.get().is_empty() {
+                let _ = entry.remove();
+            }
+            previous
+        };
+        if let Some(previous) = previous {
+            do_set(previous)?;
+        }
+        Ok(false)
+    }
+}
+
+/// The `__doc__` of [`WrappedSignal`]: the class docstring, or a summary of the signal
+///
+/// `pyo3` stores the class docstring in the type's `__doc__` after all methods were added,
+/// so a `#[getter]` cannot be used.
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug)]
+struct SignalDoc {
+    class_doc: PyObject,
+}
+
+#[pymethods]
+impl SignalDoc {
+    fn __get__(
+        &self,
+        instance: Option<&Bound<'_, PyAny>>,
+        _owner: Option<&Bound<'_, PyAny>>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        match instance.map(Bound::downcast::<WrappedSignal>) {
+            Some(Ok(signal)) => Ok(signal.get().doc(py)?.into_py(py)),
+            _ => Ok(self.class_doc.clone_ref(py)),
+        }
+    }
+}
+
+/// The previous settings of active `with signal:` blocks, per thread
+///
+/// Signals are singletons, so the state cannot be stored in the instance.
+/// The parent-death signal is a per-thread setting, so each thread restores only its own.
+fn signal_scopes() -> &'static Mutex<HashMap<ThreadId, Vec<Option<i32>>>> {
+    static SIGNAL_SCOPES: OnceLock<Mutex<HashMap<ThreadId, Vec<Option<i32>>>>> = OnceLock::new();
+    SIGNAL_SCOPES.get_or_init(Mutex::default)
 }
 
 /// Get the parent-death signal number of the calling process
 ///
 /// C.f. <https://www.man7.org/linux/man-pages//man2/PR_SET_PDEATHSIG.2const.html>
+/// and <https://man.freebsd.org/cgi/man.cgi?query=procctl&sektion=2> on FreeBSD
+///
+/// Raises `NotImplementedError` if the platform is not [`supported()`].
+///
+/// If `cached` is true, then the result of a previous call is reused, unless the signal was
+/// changed through this module in the meantime. Changes made by other means, e.g. by calling
+/// `prctl()` directly or by forking, are not noticed.
+///
+/// On Linux a real-time signal is only reported if it was set through this module, e.g. not if it
+/// was inherited across `exec()`, because reading it is several times slower than for the others.
+///
+/// If no signal is set, then `default` is returned, which is normalized like the argument
+/// of [`set()`], so `get(default="TERM")` returns `Signal.SIGTERM`.
+///
+/// If `required` is true, then a `LookupError` is raised instead of returning `None`.
+/// If `use_sentinel` is true, then [`DISABLED`](DisabledType) is returned instead of `None`.
 #[pyfunction]
-#[pyo3(name = "get")]
-fn get(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
-    do_get(py)
+#[pyo3(
+    name = "get",
+    signature = (*, cached = false, default = None, required = false, use_sentinel = false)
+)]
+fn get(
+    cached: bool,
+    default: Option<SignalArg>,
+    required: bool,
+    use_sentinel: bool,
+    py: Python<'_>,
+) -> PyResult<Option<Either<Py<WrappedSignal>, Py<DisabledType>>>> {
+    let default = to_signal(default)?;
+    let signal = if cached {
+        cached_get_raw(py)?
+    } else {
+        do_get_raw(py)?
+    };
+    match signal.or(default) {
+        Some(signal) => Ok(Some(Either::Left(WrappedSignal::from_number(py, signal)?))),
+        None if required => Err(PyLookupError::new_err(("no parent-death signal is set",))),
+        None if use_sentinel => Ok(Some(Either::Right(DisabledType::get(py)?))),
+        None => Ok(None),
+    }
 }
 
-/// Set the parent-death signal number of the calling process
+/// The type of [`DISABLED`](DisabledType::get), which stands for "no parent-death signal"
 ///
-/// C.f. <https://www.man7.org/linux/man-pages/man2/PR_GET_PDEATHSIG.2const.html>
-#[pyfunction]
-#[pyo3(name = "set", signature = (signal, /))]
-fn set(signal: Option<Either<WrappedSignal, i32>>) -> PyResult<()> {
-    do_set(match signal {
-        None | Some(Either::Right(0)) => None,
-        Some(Either::Left(WrappedSignal(signal))) => Some(signal),
-        Some(Either::Right(signal)) => match Signal::from_raw(signal) {
-            Some(signal) => Some(signal),
-            None => {
-                return Err(PyValueError::new_err((format!(
-                    "Illegal signal number {signal}"
-                ),)));
-            },
-        },
-    })
-}
+/// [`set()`] and all other functions that take a signal accept `DISABLED` like `None`.
+/// It is falsy, like `None`.
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug, Clone, Copy)]
+struct DisabledType;
 
-fn do_get(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
-    match parent_process_death_signal() {
-        Ok(Some(signal)) => Ok(Some(WrappedSignal::from_signal(py, signal)?)),
-        Ok(None) => Ok(None),
-        Err(err) => Err(PyOSError::new_err((err.raw_os_error(), err.to_string()))),
+#[pymethods]
+impl DisabledType {
+    fn __bool__(&self) -> bool {
+        false
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "pdeathsignal.DISABLED"
+    }
+
+    // pickled by reference to the module attribute, so that it stays a singleton
+    fn __reduce__(&self) -> &'static str {
+        "DISABLED"
+    }
+
+    fn __copy__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __deepcopy__(slf: Py<Self>, _memo: &Bound<'_, PyAny>) -> Py<Self> {
+        slf
     }
 }
 
-fn do_set(signal: Option<Signal>) -> PyResult<()> {
-    set_parent_process_death_signal(signal)
-        .map_err(|err| PyOSError::new_err((err.raw_os_error(), err.to_string())))
+impl DisabledType {
+    /// The singleton `pdeathsignal.DISABLED`
+    fn get(py: Python<'_>) -> PyResult<Py<Self>> {
+        static DISABLED: GILOnceCell<Py<DisabledType>> = GILOnceCell::new();
+        DISABLED
+            .get_or_try_init(py, || Py::new(py, DisabledType))
+            .map(|disabled| disabled.clone_ref(py))
+    }
 }
 
-impl WrappedSignal {
-    fn from_signal(py: Python<'_>, signal: Signal) -> PyResult<Py<Self>> {
-        static SIGNALS: OnceLock<PyResult<ArrayVec<Py<WrappedSignal>, SIGNAL_COUNT>>> =
-            OnceLock::new();
-        match SIGNALS.get_or_init(|| make_signals(py)) {
-            Ok(signals) => Ok(signals[signal as i32 as usize].clone_ref(py)),
-            Err(err) => Err(err.clone_ref(py)),
-        }
+fn cached_get_raw(py: Python<'_>) -> PyResult<Option<i32>> {
+    let generation = SET_GENERATION.load(Ordering::Acquire);
+    match GET_CACHE.get() {
+        Some((cached_generation, signal)) if cached_generation == generation => Ok(signal),
+        _ => {
+            let signal = do_get_raw(py)?;
+            GET_CACHE.set(Some((generation, signal)));
+            Ok(signal)
+        },
     }
 }
 
-#[cold]
-fn make_signals(py: Python<'_>) -> Result<ArrayVec<Py<WrappedSignal>, SIGNAL_COUNT>, PyErr> {
-    (0..SIGNAL_COUNT)
-        .map(|signal| Signal::from_raw(signal as i32))
-        .map(|signal| signal.unwrap_or(Signal::Hup))
-        .map(|signal| Py::new(py, WrappedSignal(signal)))
-        .collect::<PyResult<ArrayVec<_, SIGNAL_COUNT>>>()
+/// Incremented by every successful `do_set()` to invalidate [`GET_CACHE`]
+static SET_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The result of `get(cached=True)`, and the [`SET_GENERATION`] it was read in
+    static GET_CACHE: Cell<Option<(u64, Option<i32>)>> = const { Cell::new(None) };
 }
 
+/// Get the parent-death signal number of the calling process as a plain `int`
+///
+/// This is the fast path of [`get()`]: no [`WrappedSignal`] is looked up or created, and the GIL
+/// is not released for the system call, which takes less time than releasing the GIL.
+/// Returns `None` if no parent-death signal is set.
+#[pyfunction]
+#[pyo3(name = "get_raw")]
+fn get_raw(py: Python<'_>) -> PyResult<Option<i32>> {
+    peek_raw(py)
+}
+
+/// Whether a parent-death signal is set for the calling process
+///
+/// This is cheaper than `get() is not None`, because no [`WrappedSignal`] is looked up,
+/// and the GIL is not released, same as in [`get_raw()`].
+#[pyfunction]
+#[pyo3(name = "is_set")]
+fn is_set(py: Python<'_>) -> PyResult<bool> {
+    Ok(peek_raw(py)?.is_some())
+}
+
+/// Set the parent-death signal number of the calling process
+///
+/// C.f. <https://www.man7.org/linux/man-pages/man2/PR_GET_PDEATHSIG.2const.html>
+/// and <https://man.freebsd.org/cgi/man.cgi?query=procctl&sektion=2> on FreeBSD
+///
+/// Raises `NotImplementedError` if the platform is not [`supported()`].
+///
+/// `signal` is converted like in [`WrappedSignal::parse`], so a mnemonic like `"TERM"` or a
+/// decimal string like `"15"` is accepted, too. `None` and `0` clear the parent-death signal.
+///
+/// The parent-death signal is delivered when the thread that created the calling process exits,
+/// not necessarily when the whole parent process exits. If `require_main_thread` is true, then
+/// a `RuntimeError` is raised if `set()` is not called from the main thread.
+///
+/// If `strict` is true, then a `RuntimeError` is raised if a signal was already set by any function
+/// of this module without an intervening [`clear()`], to catch double-initialization bugs.
+/// A child forked with `os.fork()` starts over, like the kernel clears the signal there.
+///
+/// A `RuntimeWarning` is emitted if the signal cannot be handled, i.e. for SIGKILL and SIGSTOP,
+/// unless `quiet` is true.
+#[pyfunction]
+#[pyo3(
+    name = "set",
+    signature = (signal, /, *, require_main_thread = false, strict = false, quiet = false)
+)]
+fn set(
+    signal: Option<SignalArg>,
+    require_main_thread: bool,
+    strict: bool,
+    quiet: bool,
+    py: Python<'_>,
+) -> PyResult<()> {
+    let signal = to_signal(signal)?;
+    if require_main_thread && !is_main_thread(py)? {
+        return Err(PyRuntimeError::new_err((
+            "set() must be called from the main thread",
+        )));
+    }
+    if strict && SIGNAL_WAS_SET.load(Ordering::Acquire) {
+        return Err(PyRuntimeError::new_err((
+            "the parent-death signal was already set, call clear() first",
+        )));
+    }
+    warn_and_set(py, signal, !quiet)
+}
+
+/// Emit the warning of [`warn_if_uncatchable()`] if `warn` is true, then set the signal
+///
+/// This is the common path of [`set()`] and [`Policy::apply()`].
+fn warn_and_set(py: Python<'_>, signal: Option<i32>, warn: bool) -> PyResult<()> {
+    if let Some(signal) = signal.filter(|_| warn) {
+        warn_if_uncatchable(py, signal)?;
+    }
+    do_set(signal)
+}
+
+/// Like [`set()`], but return `(True, 0)` on success, or `(False, errno)` if the OS rejected
+/// the signal, instead of raising an `OSError`
+///
+/// Malformed arguments still raise `TypeError` or `ValueError`. This avoids the cost of
+/// exceptions in tight loops.
+#[pyfunction]
+#[pyo3(name = "try_set", signature = (signal, /))]
+fn try_set(signal: Option<SignalArg>) -> PyResult<(bool, i32)> {
+    let signal = to_signal(signal)?;
+    match try_do_set(signal)? {
+        Ok(()) => Ok((true, 0)),
+        Err(err) => Ok((false, err.raw_os_error())),
+    }
+}
+
+/// Emit a `RuntimeWarning` if `signal` cannot be handled, i.e. for SIGKILL and SIGSTOP
+fn warn_if_uncatchable(py: Python<'_>, signal: i32) -> PyResult<()> {
+    if WrappedSignal(signal).is_catchable() {
+        return Ok(());
+    }
+    let msg = format!(
+        "{} cannot be handled by the process",
+        WrappedSignal(signal).name()
+    );
+    PyErr::warn_bound(py, &py.get_type_bound::<PyRuntimeWarning>(), &msg, 1)
+}
+
+/// Whether the last change made by this module set a signal, as opposed to clearing it
+///
+/// Reset in a forked child by [`forget_signal_after_fork()`].
+static SIGNAL_WAS_SET: AtomicBool = AtomicBool::new(false);
+
+/// Registered as `after_in_child` hook with `os.register_at_fork()` when the module is loaded
+#[cfg(unix)]
+#[pyfunction]
+fn forget_signal_after_fork() {
+    SIGNAL_WAS_SET.store(false, Ordering::Release);
+}
+
+/// Set the first signal of `signals` that is accepted as parent-death signal, and return it
+///
+/// Invalid signals and signals that could not be set are skipped.
+/// `signals` must not be a single `str`.
+/// If no signal could be set, then the error of the last attempt is raised.
+#[pyfunction]
+#[pyo3(name = "set_first_supported", signature = (signals, /))]
+fn set_first_supported<'py>(
+    signals: &Bound<'py, PyAny>,
+    py: Python<'py>,
+) -> PyResult<Py<WrappedSignal>> {
+    let mut last_err = None;
+    for signal in iter_signals(signals)? {
+        let signal = match element_to_signal(&signal?) {
+            Ok(signal) => signal,
+            Err(err) => {
+                last_err = Some(err);
+                continue;
+            },
+        };
+        match do_set(Some(signal)) {
+            Ok(()) => return WrappedSignal::from_number(py, signal),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| PyValueError::new_err(("no signals given",))))
+}
+
+/// Iterate over a list of signals, which must not be a single signal name
+fn iter_signals<'py>(signals: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyIterator>> {
+    if signals.is_instance_of::<PyString>() {
+        // `set_first_supported("TERM")` would try "T", "E", "R", and "M"
+        return Err(PyTypeError::new_err((
+            "signals must be an iterable of signals, not str",
+        )));
+    }
+    signals.iter()
+}
+
+/// Validate an element of [`iter_signals()`]; `None` and `0` are not accepted
+fn element_to_signal(signal: &Bound<'_, PyAny>) -> PyResult<i32> {
+    match to_signal(signal.extract()?)? {
+        Some(signal) => Ok(signal),
+        None => Err(illegal_signal(0)),
+    }
+}
+
+/// A reusable configuration: a list of fallback signals, and whether to warn about signals that
+/// cannot be handled
+///
+/// `Policy([Signal.SIGTERM, Signal.SIGHUP]).apply()` is the same as
+/// `set_first_supported([Signal.SIGTERM, Signal.SIGHUP])`, but the signals are validated once,
+/// when the policy is created.
+///
+/// Invalid signals are skipped. If no signal is valid, then the error of the last one is raised.
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug)]
+struct Policy {
+    signals: Vec<i32>,
+    warn_noncatchable: bool,
+}
+
+#[pymethods]
+impl Policy {
+    #[new]
+    #[pyo3(signature = (signals, *, warn_noncatchable = true))]
+    fn __new__(signals: &Bound<'_, PyAny>, warn_noncatchable: bool) -> PyResult<Self> {
+        let mut last_err = None;
+        let mut valid = Vec::new();
+        for signal in iter_signals(signals)? {
+            match element_to_signal(&signal?) {
+                Ok(signal) => valid.push(signal),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        if valid.is_empty() {
+            return Err(last_err.unwrap_or_else(|| PyValueError::new_err(("no signals given",))));
+        }
+        Ok(Self {
+            signals: valid,
+            warn_noncatchable,
+        })
+    }
+
+    /// The signals in the order they are tried
+    #[getter]
+    fn signals(&self, py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+        self.signals
+            .iter()
+            .map(|&signal| WrappedSignal::from_number(py, signal))
+            .collect()
+    }
+
+    /// Whether [`apply()`](Self::apply) emits a `RuntimeWarning` for SIGKILL and SIGSTOP
+    #[getter]
+    fn warn_noncatchable(&self) -> bool {
+        self.warn_noncatchable
+    }
+
+    /// Set the first signal that is accepted as parent-death signal, and return it
+    ///
+    /// If no signal could be set, then the error of the last attempt is raised.
+    fn apply(&self, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        let mut last_err = None;
+        for &signal in &self.signals {
+            match warn_and_set(py, Some(signal), self.warn_noncatchable) {
+                Ok(()) => return WrappedSignal::from_number(py, signal),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| PyValueError::new_err(("no signals given",))))
+    }
+
+    fn __repr__(&self) -> String {
+        let signals = self
+            .signals
+            .iter()
+            .map(|&signal| WrappedSignal(signal).__repr__())
+            .collect::<Vec<_>>();
+        format!(
+            "pdeathsignal.Policy([{}], warn_noncatchable={})",
+            signals.join(", "),
+            if self.warn_noncatchable {
+                "True"
+            } else {
+                "False"
+            },
+        )
+    }
+}
+
+/// Set the parent-death signal to the value of the environment variable `var`
+///
+/// The value is parsed like [`WrappedSignal::parse`], i.e. it can be a mnemonic or a number.
+/// Returns `False` if the variable is not set, and `True` if the signal was set.
+#[pyfunction]
+#[pyo3(name = "set_from_env", signature = (var = "PDEATHSIG"))]
+fn set_from_env(var: &str, py: Python<'_>) -> PyResult<bool> {
+    let Some(value) = std::env::var_os(var) else {
+        return Ok(false);
+    };
+    let Ok(value) = value.into_string() else {
+        return Err(PyValueError::new_err((format!(
+            "environment variable {var} is not valid UTF-8"
+        ),)));
+    };
+    let signal = signal_from_raw(SignalArg::Name(value).number()?)?;
+    set(Some(SignalArg::Number(signal)), false, false, false, py)?;
+    Ok(true)
+}
+
+/// Set the parent-death signal number of the calling process, and return the previous one
+///
+/// Like `signal.signal()` this returns the previous setting, or `None` if none was set.
+#[pyfunction]
+#[pyo3(name = "set_and_get", signature = (signal, /))]
+fn set_and_get(signal: Option<SignalArg>, py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    let signal = to_signal(signal)?;
+    let previous = do_get(py)?;
+    do_set(signal)?;
+    Ok(previous)
+}
+
+/// Clear the parent-death signal of the calling process
+///
+/// This is the canonical way to disable the parent-death signal, equivalent to `set(None)`.
+#[pyfunction]
+#[pyo3(name = "clear")]
+fn clear() -> PyResult<()> {
+    do_set(None)
+}
+
+/// Whether `signal` is accepted as parent-death signal
+///
+/// The signal is set tentatively, and the previous setting is restored afterwards.
+/// The trace hook is not called for the tentative change.
+/// Returns `False` for invalid signals, if setting the signal failed,
+/// or if the platform is not [`supported()`].
+#[pyfunction]
+#[pyo3(name = "can_set", signature = (signal, /))]
+fn can_set(signal: Option<SignalArg>, py: Python<'_>) -> PyResult<bool> {
+    let Ok(signal) = to_signal(signal) else {
+        return Ok(false);
+    };
+    if !supported() {
+        return Ok(false);
+    }
+    // Don't use `do_set()`, so the trace hook does not see the tentative change.
+    let previous = do_get_raw(py)?;
+    if sys_set(py, signal)?.is_err() {
+        return Ok(false);
+    }
+    if let Err(err) = sys_set(py, previous)? {
+        // the tentative signal is still in effect
+        signal_changed(signal);
+        return Err(os_error(err));
+    }
+    Ok(true)
+}
+
+/// Clear the parent-death signal and all state kept by this module, and return the previous signal
+///
+/// This also forgets about signals set with `strict=True`, disables all [`keep_across_fork()`]
+/// hooks, removes the [`set_trace_hook()`], and forgets the previous settings of active
+/// `with signal:` blocks.
+/// Meant for test isolation, e.g. in a pytest fixture:
+///
+/// ```python
+/// @pytest.fixture(autouse=True)
+/// def pdeathsig():
+///     yield
+///     pdeathsignal.reset()
+/// ```
+#[pyfunction]
+#[pyo3(name = "reset")]
+fn reset(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    set_trace_hook(None);
+    #[cfg(linux_kernel)]
+    signal_owners()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+    SIGNAL_WAS_SET.store(false, Ordering::Release);
+    let _ = FORK_HOOK_GENERATION.fetch_add(1, Ordering::AcqRel);
+    signal_scopes()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+    let previous = do_get(py)?;
+    do_set(None)?;
+    Ok(previous)
+}
+
+/// Check that the parent-death signal is actually delivered in this environment
+///
+/// A helper process is forked, which forks a child that sets SIGUSR1 as its parent-death signal.
+/// Then the helper exits, and the child reports whether it received SIGUSR1 within `timeout`
+/// seconds. Returns a tuple `(delivered, diagnostic_message)`.
+#[pyfunction]
+#[pyo3(name = "self_test", signature = (timeout = 1.0))]
+fn self_test(timeout: f64, py: Python<'_>) -> PyResult<(bool, String)> {
+    if !supported() {
+        return Ok((false, "not supported on this platform".to_owned()));
+    }
+    self_test_parent(py, timeout)
+}
+
+/// The calling process of [`self_test()`]: fork the helper, and read the child's report
+#[cfg(unix)]
+fn self_test_parent(py: Python<'_>, timeout: f64) -> PyResult<(bool, String)> {
+    let os = py.import_bound("os")?;
+    let (ready_r, ready_w): (i32, i32) = os.call_method0("pipe")?.extract()?;
+    let (result_r, result_w): (i32, i32) = os.call_method0("pipe")?.extract()?;
+    let helper: i32 = os.call_method0("fork")?.extract()?;
+    if helper == 0 {
+        let code = i32::from(self_test_helper(py, ready_r, ready_w, result_w, timeout).is_err());
+        let _ = os.call_method1("_exit", (code,));
+        // `os._exit()` does not return
+        std::process::abort();
+    }
+    for fd in [ready_r, ready_w, result_w] {
+        let _ = os.call_method1("close", (fd,))?;
+    }
+
+    let result = (|| {
+        if let Some(helper) = Pid::from_raw(helper) {
+            let _ = py
+                .allow_threads(|| waitpid(Some(helper), WaitOptions::empty()))
+                .map_err(os_error)?;
+        }
+        let select = py.import_bound("select")?;
+        let readable = select
+            .call_method1("select", ([result_r], [0; 0], [0; 0], timeout + 1.0))?
+            .get_item(0)?;
+        if !readable.is_truthy()? {
+            return Ok((false, "the test process did not respond".to_owned()));
+        }
+        let message: Vec<u8> = os.call_method1("read", (result_r, 1024))?.extract()?;
+        let message = String::from_utf8_lossy(&message);
+        Ok(match message.strip_prefix('1') {
+            Some(message) => (true, message.to_owned()),
+            None => (false, message.get(1..).unwrap_or_default().to_owned()),
+        })
+    })();
+    let _ = os.call_method1("close", (result_r,))?;
+    result
+}
+
+#[cfg(not(unix))]
+fn self_test_parent(_py: Python<'_>, _timeout: f64) -> PyResult<(bool, String)> {
+    Err(unsupported())
+}
+
+/// The intermediate process of [`self_test()`]: fork the child, and wait until it is ready
+#[cfg(unix)]
+fn self_test_helper(
+    py: Python<'_>,
+    ready_r: i32,
+    ready_w: i32,
+    result_w: i32,
+    timeout: f64,
+) -> PyResult<()> {
+    let os = py.import_bound("os")?;
+    let child: i32 = os.call_method0("fork")?.extract()?;
+    if child == 0 {
+        let message = match self_test_child(py, ready_w, timeout) {
+            Ok(true) => "1the parent-death signal was delivered".to_owned(),
+            Ok(false) => "0the parent-death signal was not delivered in time".to_owned(),
+            Err(err) => format!("0{err}"),
+        };
+        let _ = os.call_method1("write", (result_w, message.as_bytes()))?;
+        let _ = os.call_method1("_exit", (0,));
+        std::process::abort();
+    }
+    // if the child dies prematurely, `read()` returns instead of blocking forever
+    let _ = os.call_method1("close", (ready_w,))?;
+    let _ = os.call_method1("read", (ready_r, 1))?;
+    Ok(())
+}
+
+/// The child process of [`self_test()`]: wait for the parent-death signal
+#[cfg(unix)]
+fn self_test_child(py: Python<'_>, ready_w: i32, timeout: f64) -> PyResult<bool> {
+    let signal = Signal::Usr1 as i32;
+    let module = py.import_bound("signal")?;
+    let _ = module.call_method1("pthread_sigmask", (module.getattr("SIG_BLOCK")?, [signal]))?;
+    do_set(Some(signal))?;
+    let _ = py
+        .import_bound("os")?
+        .call_method1("write", (ready_w, b"x".as_slice()))?;
+    Ok(!module
+        .call_method1("sigtimedwait", ([signal], timeout))?
+        .is_none())
+}
+
+/// All signals, including the real-time signals, that match the given criteria
+///
+/// `catchable` filters by [`WrappedSignal::is_catchable`], `default_action` filters by
+/// [`WrappedSignal::default_action`]. `None` means that the criterion is not checked.
+#[pyfunction]
+#[pyo3(name = "signals", signature = (*, catchable = None, default_action = None))]
+fn signals(
+    catchable: Option<bool>,
+    default_action: Option<&str>,
+    py: Python<'_>,
+) -> PyResult<Vec<Py<WrappedSignal>>> {
+    if let Some(action) = default_action {
+        if !["term", "core", "ignore", "stop", "cont"].contains(&action) {
+            return Err(PyValueError::new_err((format!(
+                "Unknown default action {action:?}"
+            ),)));
+        }
+    }
+    (1..nsig())
+        .filter(|&signal| signal_from_raw(signal).is_ok())
+        .map(WrappedSignal)
+        .filter(|signal| catchable.is_none_or(|catchable| signal.is_catchable() == catchable))
+        .filter(|signal| default_action.is_none_or(|action| signal.default_action() == action))
+        .map(|signal| WrappedSignal::from_number(py, signal.0))
+        .collect()
+}
+
+/// The signals that are sensible choices for a parent-death signal
+///
+/// These are all signals that can be caught, and terminate the process by default,
+/// e.g. SIGTERM, SIGHUP or SIGUSR1, but not SIGCHLD, SIGCONT or SIGKILL.
+#[pyfunction]
+#[pyo3(name = "recommended_signals")]
+fn recommended_signals(py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+    (1..nsig())
+        .filter(|&signal| signal_from_raw(signal).is_ok())
+        .map(WrappedSignal)
+        .filter(|signal| signal.is_catchable())
+        .filter(|signal| matches!(signal.default_action(), "term" | "core"))
+        .map(|signal| WrappedSignal::from_number(py, signal.0))
+        .collect()
+}
+
+/// The signals that are currently blocked in the calling thread, sorted by their number
+///
+/// A blocked parent-death signal stays pending until it is unblocked, so this helps to find out
+/// why the signal did not fire. Signals reserved by the C library are omitted.
+#[pyfunction]
+#[pyo3(name = "blocked_signals")]
+fn blocked_signals(py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+    let mut blocked = blocked_signal_numbers(py)?;
+    blocked.sort_unstable();
+    blocked
+        .into_iter()
+        .filter(|&signal| signal_from_raw(signal).is_ok())
+        .map(|signal| WrappedSignal::from_number(py, signal))
+        .collect()
+}
+
+/// Unblock the configured parent-death signal in the calling thread, if it is blocked
+///
+/// Returns `True` if the signal was unblocked, and `False` if it was not blocked,
+/// or if no parent-death signal is set.
+#[pyfunction]
+#[pyo3(name = "ensure_deliverable")]
+fn ensure_deliverable(py: Python<'_>) -> PyResult<bool> {
+    let Some(configured) = do_get_raw(py)? else {
+        return Ok(false);
+    };
+    if !blocked_signal_numbers(py)?.contains(&configured) {
+        return Ok(false);
+    }
+    let signal = py.import_bound("signal")?;
+    let _ = signal.call_method1(
+        "pthread_sigmask",
+        (signal.getattr("SIG_UNBLOCK")?, (configured,)),
+    )?;
+    Ok(true)
+}
+
+/// Install a callback `hook(action, old, new)` that is called after every change of the
+/// parent-death signal through this module
+///
+/// `action` is `"set"` or `"clear"`, `old` and `new` are the previous and new `Signal | None`.
+/// Exceptions raised by the hook are propagated to the caller, after the signal was changed.
+/// Passing `None` removes the hook.
+#[pyfunction]
+#[pyo3(name = "set_trace_hook", signature = (hook, /))]
+fn set_trace_hook(hook: Option<PyObject>) {
+    let previous = mem::replace(
+        &mut *TRACE_HOOK.lock().unwrap_or_else(PoisonError::into_inner),
+        hook,
+    );
+    // dropping the previous hook may run arbitrary code, so the lock must be released first
+    drop(previous);
+}
+
+static TRACE_HOOK: Mutex<Option<PyObject>> = Mutex::new(None);
+
+/// Whether the parent-death signal is supported on this platform
+///
+/// If not, then all functions that get or set the parent-death signal raise `NotImplementedError`.
+#[pyfunction]
+#[pyo3(name = "supported")]
+fn supported() -> bool {
+    cfg!(pdeathsig)
+}
+
+/// The process ID of the parent process, or 0 if there is none
+///
+/// The parent may die before the parent-death signal was set, so the signal would never fire.
+/// To close this race, capture the parent's PID first, then call [`set()`], and finally check
+/// [`parent_is_alive()`] with the captured PID.
+#[pyfunction]
+#[pyo3(name = "getppid")]
+fn getppid() -> i32 {
+    current_ppid()
+}
+
+/// The session ID of the process `pid`, or of the calling process if `pid` is 0
+///
+/// Like `os.getsid()`, but raises the same exceptions as the other functions of this module.
+#[pyfunction]
+#[pyo3(name = "getsid", signature = (pid = 0, /))]
+fn getsid(pid: i32) -> PyResult<i32> {
+    #[cfg(unix)]
+    {
+        let pid = pid_arg(pid)?;
+        let id = rustix::process::getsid(pid).map_err(os_error)?;
+        Ok(Pid::as_raw(Some(id)))
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        Err(PyNotImplementedError::new_err((
+            "getsid() is not supported on this platform",
+        )))
+    }
+}
+
+/// The process group ID of the process `pid`, or of the calling process if `pid` is 0
+///
+/// Like `os.getpgid()`, but raises the same exceptions as the other functions of this module.
+#[pyfunction]
+#[pyo3(name = "getpgid", signature = (pid = 0, /))]
+fn getpgid(pid: i32) -> PyResult<i32> {
+    #[cfg(unix)]
+    {
+        let pid = pid_arg(pid)?;
+        let id = rustix::process::getpgid(pid).map_err(os_error)?;
+        Ok(Pid::as_raw(Some(id)))
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        Err(PyNotImplementedError::new_err((
+            "getpgid() is not supported on this platform",
+        )))
+    }
+}
+
+/// Whether the parent process is still `original_ppid`
+///
+/// If the parent died, then the calling process was reparented, typically to PID 1.
+#[pyfunction]
+#[pyo3(name = "parent_is_alive", signature = (original_ppid, /))]
+fn parent_is_alive(original_ppid: i32) -> bool {
+    current_ppid() == original_ppid
+}
+
+/// Set the parent-death signal, and raise it right away if the parent already exited
+///
+/// This closes the race between forking and calling [`set()`]: the parent's PID is captured
+/// before setting the signal, and checked again afterwards. If the calling process was
+/// reparented in between, then `signal` is sent to the calling process immediately,
+/// as if the parent had died after `set()`.
+///
+/// To cover the time between `fork()` and this call, too, pass the PID the parent had before
+/// forking, i.e. its `os.getpid()`, as `original_ppid`.
+///
+/// Returns `True` if the parent was found to have exited.
+#[pyfunction]
+#[pyo3(name = "set_if_orphaned", signature = (signal, /, original_ppid = None))]
+fn set_if_orphaned(
+    signal: Option<SignalArg>,
+    original_ppid: Option<i32>,
+    py: Python<'_>,
+) -> PyResult<bool> {
+    let signal = to_signal(signal)?;
+    let original_ppid = original_ppid.unwrap_or_else(current_ppid);
+    do_set(signal)?;
+    if current_ppid() == original_ppid {
+        return Ok(false);
+    }
+    if let Some(signal) = signal {
+        raise_signal(py, signal)?;
+    }
+    Ok(true)
+}
+
+/// Send the configured parent-death signal to the calling process, as if the parent had died
+///
+/// This lets tests exercise the handling of the parent's death without killing the parent.
+/// Returns the signal that was sent, or `None` if no parent-death signal is set.
+#[pyfunction]
+#[pyo3(name = "raise_configured")]
+fn raise_configured(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    let signal = do_get(py)?;
+    if let Some(signal) = &signal {
+        raise_signal(py, signal.get().0)?;
+    }
+    Ok(signal)
+}
+
+/// The thread ID of the thread that most recently set the parent-death signal through this module,
+/// or `None` if every thread that set it cleared it again, or if it was not set in this process
+///
+/// On Linux, the parent-death signal is an attribute of the thread that set it, not of the
+/// whole process, so this helps to find out that e.g. a worker thread set it.
+/// Always `None` on other platforms.
+#[pyfunction]
+#[pyo3(name = "owning_tid")]
+fn owning_tid() -> Option<i32> {
+    #[cfg(linux_kernel)]
+    {
+        // the owners of the parent process are meaningless in a forked child
+        let pid = getpid();
+        signal_owners()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|(_, &(owner_pid, _))| owner_pid == pid)
+            .max_by_key(|(_, &(_, generation))| generation)
+            .map(|(&tid, _)| Pid::as_raw(Some(tid)))
+    }
+    #[cfg(not(linux_kernel))]
+    None
+}
+
+/// The threads that set the parent-death signal through [`do_set()`], and did not clear it since
+///
+/// Maps the thread ID to the process ID and the [`SET_GENERATION`] of the change.
+#[cfg(linux_kernel)]
+fn signal_owners() -> &'static Mutex<HashMap<Pid, (Pid, u64)>> {
+    static SIGNAL_OWNERS: OnceLock<Mutex<HashMap<Pid, (Pid, u64)>>> = OnceLock::new();
+    SIGNAL_OWNERS.get_or_init(Mutex::default)
+}
+
+/// Wait until the parent process exits
+///
+/// Returns an `asyncio.Future` of the running event loop that is resolved when the parent exits.
+///
+/// Raises `NotImplementedError` if `pidfd_open(2)` is not supported.
+#[pyfunction]
+#[pyo3(name = "wait_parent_exit")]
+fn wait_parent_exit(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    let event_loop = py
+        .import_bound("asyncio")?
+        .call_method0("get_running_loop")?;
+    let future = event_loop.call_method0("create_future")?;
+    match open_parent_pidfd()? {
+        Some(pidfd) => {
+            let fd = pidfd.as_raw_fd();
+            let waker = Bound::new(py, ParentExitWaker {
+                pidfd: Mutex::new(Some(pidfd)),
+                event_loop: event_loop.clone().unbind(),
+                future: future.clone().unbind(),
+            })?;
+            let _ = event_loop.call_method1("add_reader", (fd, &waker))?;
+            let _ = future.call_method1("add_done_callback", (&waker,))?;
+        },
+        None => {
+            let _ = future.call_method1("set_result", (py.None(),))?;
+        },
+    }
+    Ok(future)
+}
+
+/// Block until the parent process exits, or until `timeout` seconds have passed
+///
+/// Returns `True` if the parent exited, and `False` if the timeout expired.
+/// `timeout=None` or `float("inf")` waits indefinitely. This is the synchronous counterpart to
+/// [`wait_parent_exit()`], e.g. for shutdown sequences.
+///
+/// Raises `NotImplementedError` if `pidfd_open(2)` is not supported.
+#[pyfunction]
+#[pyo3(name = "wait_parent_exit_blocking", signature = (timeout = None))]
+fn wait_parent_exit_blocking(timeout: Option<f64>, py: Python<'_>) -> PyResult<bool> {
+    // `poll()` would treat a negative timeout as infinite
+    if timeout.is_some_and(|timeout| timeout.is_nan() || timeout < 0.0) {
+        return Err(PyValueError::new_err(("timeout must be non-negative",)));
+    }
+    // `poll()` would raise an `OverflowError`
+    let timeout = timeout.filter(|timeout| timeout.is_finite());
+    let Some(pidfd) = open_parent_pidfd()? else {
+        return Ok(true);
+    };
+    // `select.poll()` releases the GIL, and retries with the remaining timeout after a signal
+    let select = py.import_bound("select")?;
+    let poll = select.call_method0("poll")?;
+    let _ = poll.call_method1("register", (pidfd.as_raw_fd(), select.getattr("POLLIN")?))?;
+    let events = poll.call_method1("poll", (timeout.map(|timeout| timeout * 1000.0),))?;
+    events.is_truthy()
+}
+
+/// Open a file descriptor that becomes readable when the parent process exits
+///
+/// The descriptor is a pidfd of the parent process, which can be registered with `select`,
+/// `selectors` or an event loop. The caller owns the descriptor, and must close it with
+/// `os.close()`.
+///
+/// Raises `ProcessLookupError` if the parent already exited,
+/// and `NotImplementedError` if `pidfd_open(2)` is not supported.
+#[pyfunction]
+#[pyo3(name = "parent_death_fd")]
+fn parent_death_fd() -> PyResult<i32> {
+    match open_parent_pidfd()? {
+        Some(pidfd) => Ok(pidfd.into_raw_fd()),
+        None => Err(PyProcessLookupError::new_err((
+            "the parent process already exited",
+        ))),
+    }
+}
+
+/// Resolves the future returned by [`wait_parent_exit()`]
+///
+/// It's called by the event loop when the pidfd becomes readable, and when the future is done,
+/// e.g. because it was cancelled.
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug)]
+struct ParentExitWaker {
+    pidfd: Mutex<Option<OwnedFd>>,
+    event_loop: PyObject,
+    future: PyObject,
+}
+
+#[pymethods]
+impl ParentExitWaker {
+    #[pyo3(signature = (*_args))]
+    fn __call__(&self, py: Python<'_>, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        let pidfd = self
+            .pidfd
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        let Some(pidfd) = pidfd else {
+            return Ok(());
+        };
+        let _ = self
+            .event_loop
+            .call_method1(py, "remove_reader", (pidfd.as_raw_fd(),))?;
+        drop(pidfd);
+
+        let future = self.future.bind(py);
+        if !future.call_method0("done")?.is_truthy()? {
+            let _ = future.call_method1("set_result", (py.None(),))?;
+        }
+        Ok(())
+    }
+}
+
+/// Set the parent-death signal number of the calling process for the duration of a `with` block
+///
+/// The previous setting is restored when the block is left, even if it raised an exception.
+#[pyfunction]
+#[pyo3(name = "scoped", signature = (signal, /))]
+fn scoped(signal: Option<SignalArg>) -> PyResult<Scoped> {
+    Ok(Scoped {
+        signal: to_signal(signal)?,
+        previous: None,
+    })
+}
+
+/// Clear the parent-death signal for the duration of a `with` block, e.g. for a critical section
+///
+/// This is the same as `scoped(None)`: the previous setting is restored when the block is left,
+/// even if it raised an exception.
+#[pyfunction]
+#[pyo3(name = "suspended")]
+fn suspended() -> Scoped {
+    Scoped {
+        signal: None,
+        previous: None,
+    }
+}
+
+/// A context manager returned by [`scoped()`] and [`suspended()`]
+#[pyclass(module = "pdeathsignal")]
+#[derive(Debug)]
+struct Scoped {
+    signal: Option<i32>,
+    previous: Option<Option<Py<WrappedSignal>>>,
+}
+
+#[pymethods]
+impl Scoped {
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        let previous = do_get(slf.py())?;
+        do_set(slf.signal)?;
+        slf.previous = Some(previous);
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, _args: &Bound<'_, PyTuple>) -> PyResult<bool> {
+        if let Some(previous) = self.previous.take() {
+            do_set(previous.map(|previous| previous.get().0))?;
+        }
+        Ok(false)
+    }
+}
+
+/// Like [`scoped()`], but send `signal` to the calling process when the block is left,
+/// if the parent process exited in the meantime
+///
+/// The parent's PID is captured when the block is entered, or `original_ppid` is used if given,
+/// cf. [`set_if_orphaned()`]. When the block is left, the previous parent-death signal is
+/// restored in any case. Then, if the calling process was reparented, `signal` is sent to it,
+/// even if the kernel already delivered the parent-death signal during the block.
+/// If the parent is still alive, leaving the block only restores the previous setting.
+#[pyfunction]
+#[pyo3(name = "guard", signature = (signal, /, original_ppid = None))]
+fn guard(signal: Option<SignalArg>, original_ppid: Option<i32>) -> PyResult<Guard> {
+    Ok(Guard {
+        signal: to_signal(signal)?,
+        original_ppid,
+        previous: None,
+    })
+}
+
+/// A context manager returned by [`guard()`]
+#[pyclass(module = "pdeathsignal")]
+#[derive(Debug)]
+struct Guard {
+    signal: Option<i32>,
+    original_ppid: Option<i32>,
+    previous: Option<Option<Py<WrappedSignal>>>,
+}
+
+#[pymethods]
+impl Guard {
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        let original_ppid = slf.original_ppid.unwrap_or_else(current_ppid);
+        let previous = do_get(slf.py())?;
+        do_set(slf.signal)?;
+        slf.original_ppid = Some(original_ppid);
+        slf.previous = Some(previous);
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, py: Python<'_>, _args: &Bound<'_, PyTuple>) -> PyResult<bool> {
+        let Some(previous) = self.previous.take() else {
+            return Ok(false);
+        };
+        do_set(previous.map(|previous| previous.get().0))?;
+        if let Some(signal) = self.signal {
+            if self
+                .original_ppid
+                .is_some_and(|ppid| ppid != current_ppid())
+            {
+                raise_signal(py, signal)?;
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Set the parent-death signal, and install `callback` as the Python handler of that signal
+///
+/// This is a shorthand for `signal.signal(signal, callback)` followed by `set(signal)`.
+/// If setting the parent-death signal fails, then the previous handler is reinstated.
+///
+/// Like any Python signal handler, `callback(signum, frame)` is always run in the main thread,
+/// and `on_parent_death()` itself must be called from the main thread, too.
+///
+/// Call `remove()` on the returned handle to restore the previous handler and parent-death signal.
+#[pyfunction]
+#[pyo3(
+    name = "on_parent_death",
+    signature = (callback, signal = SignalArg::Number(Signal::Term as i32)),
+    text_signature = "(callback, signal=\"SIGTERM\")"
+)]
+fn on_parent_death(
+    callback: &Bound<'_, PyAny>,
+    signal: SignalArg,
+    py: Python<'_>,
+) -> PyResult<ParentDeathHandler> {
+    let Some(signal) = to_signal(Some(signal))? else {
+        return Err(illegal_signal(0));
+    };
+    let previous_signal = do_get(py)?;
+
+    let module = py.import_bound("signal")?;
+    let previous_handler = module.call_method1("signal", (signal, callback))?.unbind();
+    if let Err(err) = do_set(Some(signal)) {
+        let _ = module.call_method1("signal", (signal, previous_handler))?;
+        return Err(err);
+    }
+
+    Ok(ParentDeathHandler {
+        signal,
+        previous: Some((previous_handler, previous_signal)),
+    })
+}
+
+/// A handle returned by [`on_parent_death()`]
+#[pyclass(module = "pdeathsignal")]
+#[derive(Debug)]
+struct ParentDeathHandler {
+    signal: i32,
+    previous: Option<(PyObject, Option<Py<WrappedSignal>>)>,
+}
+
+#[pymethods]
+impl ParentDeathHandler {
+    /// The signal the handler was installed for
+    #[getter]
+    fn signal(&self, py: Python<'_>) -> PyResult<Py<WrappedSignal>> {
+        WrappedSignal::from_number(py, self.signal)
+    }
+
+    /// Restore the previous signal handler and parent-death signal
+    ///
+    /// Calling this method more than once is a no-op.
+    fn remove(&mut self, py: Python<'_>) -> PyResult<()> {
+        if let Some((previous_handler, previous_signal)) = self.previous.take() {
+            do_set(previous_signal.map(|previous| previous.get().0))?;
+            let _ = py
+                .import_bound("signal")?
+                .call_method1("signal", (self.signal, previous_handler))?;
+        }
+        Ok(())
+    }
+}
+
+/// Set the parent-death signal in every child process forked from now on
+///
+/// The parent-death signal is cleared in the child of a `fork()`. This function registers
+/// an `after_in_child` hook with `os.register_at_fork()` that calls `set(signal)` in the child.
+/// The hook is best-effort: errors are reported through `sys.unraisablehook`, and it is not run
+/// if the child was forked without Python's knowledge, e.g. by `subprocess`.
+///
+/// Hooks registered with `os.register_at_fork()` cannot be removed, so call `unregister()` on
+/// the returned handle to make the hook a no-op.
+#[pyfunction]
+#[pyo3(name = "keep_across_fork", signature = (signal, /))]
+fn keep_across_fork(signal: Option<SignalArg>, py: Python<'_>) -> PyResult<Py<ForkHook>> {
+    let hook = Py::new(py, ForkHook {
+        signal: to_signal(signal)?,
+        active: AtomicBool::new(true),
+        generation: FORK_HOOK_GENERATION.load(Ordering::Acquire),
+    })?;
+    let kwargs = [("after_in_child", hook.clone_ref(py))].into_py_dict_bound(py);
+    let _ = py
+        .import_bound("os")?
+        .call_method("register_at_fork", (), Some(&kwargs))?;
+    Ok(hook)
+}
+
+/// A handle returned by [`keep_across_fork()`]
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug)]
+struct ForkHook {
+    signal: Option<i32>,
+    active: AtomicBool,
+    generation: u64,
+}
+
+/// Incremented by [`reset()`] to disable all existing [`ForkHook`]s
+static FORK_HOOK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[pymethods]
+impl ForkHook {
+    fn __call__(&self) -> PyResult<()> {
+        if self.active.load(Ordering::Acquire)
+            && self.generation == FORK_HOOK_GENERATION.load(Ordering::Acquire)
+        {
+            do_set(self.signal)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Stop setting the parent-death signal in forked children
+    fn unregister(&self) {
+        self.active.store(false, Ordering::Release);
+    }
+}
+
+/// An immutable set of signals
+#[pyclass(frozen, module = "pdeathsignal")]
+#[derive(Debug, Clone, Copy)]
+struct SignalSet(u64);
+
+#[pymethods]
+impl SignalSet {
+    #[new]
+    #[pyo3(signature = (signals = None, /))]
+    fn __new__(signals: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let mut mask = 0;
+        if let Some(signals) = signals {
+            for signal in signals.iter()? {
+                let Int(signal) = signal?.extract()?;
+                mask |= checked_signal_bit(signal_from_raw(signal)?)?;
+            }
+        }
+        Ok(Self(mask))
+    }
+
+    /// All signals that can be caught, i.e. all signals but SIGKILL and SIGSTOP
+    #[staticmethod]
+    fn catchable() -> Self {
+        Self(
+            (1..=64)
+                .filter(|&signal| signal_from_raw(signal).is_ok())
+                .filter(|&signal| WrappedSignal(signal).is_catchable())
+                .fold(0, |mask, signal| mask | signal_bit(signal)),
+        )
+    }
+
+    fn __contains__(&self, signal: &Bound<'_, PyAny>) -> bool {
+        match signal.extract::<i32>() {
+            Ok(signal @ 1..=64) => self.0 & signal_bit(signal) != 0,
+            _ => false,
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyIterator::from_bound_object(&PyList::new_bound(py, self.signals(py)?))
+    }
+
+    fn __and__(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    fn __or__(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.0
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (Vec<i32>,)) {
+        (slf.get_type(), (slf.get().numbers().collect(),))
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let signals = self
+            .signals(py)?
+            .iter()
+            .map(|signal| signal.get().__repr__())
+            .collect::<Vec<_>>();
+        Ok(format!("pdeathsignal.SignalSet([{}])", signals.join(", ")))
+    }
+}
+
+impl SignalSet {
+    fn numbers(&self) -> impl Iterator<Item = i32> {
+        let mask = self.0;
+        (1..=64).filter(move |&signal| mask & signal_bit(signal) != 0)
+    }
+
+    fn signals(&self, py: Python<'_>) -> PyResult<Vec<Py<WrappedSignal>>> {
+        self.numbers()
+            .map(|signal| WrappedSignal::from_number(py, signal))
+            .collect()
+    }
+
+    #[cfg(unix)]
+    fn of(signals: &[Signal]) -> Self {
+        Self(
+            signals
+                .iter()
+                .fold(0, |mask, &signal| mask | signal_bit(signal as i32)),
+        )
+    }
+}
+
+/// Signal `n` is stored in bit `n - 1`, like in `/proc/<pid>/status`
+fn signal_bit(signal: i32) -> u64 {
+    1 << (signal - 1)
+}
+
+/// Like [`signal_bit()`], but fail for signals that don't fit into 64 bits, e.g. on MIPS
+fn checked_signal_bit(signal: i32) -> PyResult<u64> {
+    match signal {
+        1..=64 => Ok(signal_bit(signal)),
+        _ => Err(PyValueError::new_err((format!(
+            "signal {} does not fit into a 64 bit mask",
+            WrappedSignal(signal).name(),
+        ),))),
+    }
+}
+
+/// Open a pidfd of the parent process, or `None` if it already exited
+#[cfg(target_os = "linux")]
+fn open_parent_pidfd() -> PyResult<Option<OwnedFd>> {
+    let Some(ppid) = rustix::process::getppid() else {
+        return Err(PyProcessLookupError::new_err((
+            "the calling process has no parent process",
+        )));
+    };
+    let pidfd = match pidfd_open(ppid, PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(Errno::SRCH) => return Ok(None),
+        Err(Errno::NOSYS) => {
+            return Err(PyNotImplementedError::new_err((
+                "pidfd_open() is not supported by the kernel",
+            )));
+        },
+        Err(err) => return Err(os_error(err)),
+    };
+    // if the parent died before `pidfd_open()`, then we might have opened an unrelated process
+    if rustix::process::getppid() == Some(ppid) {
+        Ok(Some(pidfd))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_parent_pidfd() -> PyResult<Option<OwnedFd>> {
+    Err(PyNotImplementedError::new_err((
+        "pidfd_open() is not supported on this platform",
+    )))
+}
+
+#[cfg(linux_kernel)]
+fn is_main_thread(_py: Python<'_>) -> PyResult<bool> {
+    Ok(gettid() == getpid())
+}
+
+#[cfg(not(linux_kernel))]
+fn is_main_thread(py: Python<'_>) -> PyResult<bool> {
+    let threading = py.import_bound("threading")?;
+    Ok(threading
+        .call_method0("current_thread")?
+        .is(&threading.call_method0("main_thread")?))
+}
+
+/// The signal mask of the calling thread, including signals that are unknown to us
+fn blocked_signal_numbers(py: Python<'_>) -> PyResult<Vec<i32>> {
+    // `signal.pthread_sigmask()` calls `pthread_sigmask(3)` for us, so we don't need any
+    // `unsafe` code; blocking no additional signals returns the current mask
+    let signal = py.import_bound("signal")?;
+    signal
+        .call_method1(
+            "pthread_sigmask",
+            (signal.getattr("SIG_BLOCK")?, PyList::empty_bound(py)),
+        )?
+        .iter()?
+        .map(|signal| signal?.extract())
+        .collect()
+}
+
+/// Send `signal` to the calling process
+#[cfg(pdeathsig)]
+fn raise_signal(py: Python<'_>, signal: i32) -> PyResult<()> {
+    if let Some(signal) = Signal::from_raw(signal) {
+        return kill_process(getpid(), signal).map_err(os_error);
+    }
+    // `rustix` does not know about real-time signals
+    let _ = py
+        .import_bound("os")?
+        .call_method1("kill", (Pid::as_raw(Some(getpid())), signal))?;
+    Ok(())
+}
+
+#[cfg(not(pdeathsig))]
+fn raise_signal(_py: Python<'_>, _signal: i32) -> PyResult<()> {
+    Err(unsupported())
+}
+
+/// A PID argument; 0 means the calling process, negative PIDs cannot exist
+#[cfg(unix)]
+fn pid_arg(pid: i32) -> PyResult<Option<Pid>> {
+    match pid {
+        0 => Ok(None),
+        pid => Pid::from_raw(pid)
+            .map(Some)
+            .ok_or_else(|| os_error(Errno::SRCH)),
+    }
+}
+
+#[cfg(unix)]
+fn current_ppid() -> i32 {
+    Pid::as_raw(rustix::process::getppid())
+}
+
+#[cfg(not(unix))]
+fn current_ppid() -> i32 {
+    // `std` has no portable `getppid()`, but Python has
+    Python::with_gil(|py| py.import_bound("os")?.call_method0("getppid")?.extract()).unwrap_or(0)
+}
+
+/// An integer argument, which must not be a `bool`
+///
+/// `bool` is an `int`, but e.g. `Signal.from_stdlib(True)` returning SIGHUP would be surprising.
+struct Int<T>(T);
+
+impl<'py, T: FromPyObject<'py>> FromPyObject<'py> for Int<T> {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if obj.is_instance_of::<PyBool>() {
+            return Err(PyTypeError::new_err(("expected an int, not bool",)));
+        }
+        obj.extract().map(Self)
+    }
+}
+
+/// A signal argument: a [`WrappedSignal`], its number, or its name
+#[derive(Debug)]
+enum SignalArg {
+    Signal(WrappedSignal),
+    Number(i32),
+    Name(String),
+}
+
+impl<'py> FromPyObject<'py> for SignalArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(signal) = obj.downcast::<WrappedSignal>() {
+            Ok(Self::Signal(*signal.get()))
+        } else if obj.is_instance_of::<DisabledType>() {
+            // same as `None` or `0`
+            Ok(Self::Number(0))
+        } else if let Ok(name) = obj.downcast::<PyString>() {
+            Ok(Self::Name(name.to_cow()?.into_owned()))
+        } else if obj.hasattr(intern!(obj.py(), "__index__"))? && !obj.is_instance_of::<PyBool>() {
+            // `bool` is an `int`, but `set(True)` setting SIGHUP would be surprising
+            Ok(Self::Number(obj.extract()?))
+        } else {
+            // e.g. `set(2.0)` should not complain about all the variants of this enum
+            Err(PyTypeError::new_err(format!(
+                "signal must be a Signal, int, str, or None, not {}",
+                obj.get_type().qualname()?,
+            )))
+        }
+    }
+}
+
+impl SignalArg {
+    /// The signal number, which is not validated yet, or `0`
+    fn number(self) -> PyResult<i32> {
+        match self {
+            Self::Signal(WrappedSignal(signal)) | Self::Number(signal) => Ok(signal),
+            Self::Name(name) => {
+                let name = name.trim();
+                match name.parse() {
+                    Ok(signal) => Ok(signal),
+                    Err(_) => signal_from_name(name).ok_or_else(|| unknown_signal_name(name)),
+                }
+            },
+        }
+    }
+}
+
+/// Validate a signal argument; `None` and `0` mean "no signal"
+fn to_signal(signal: Option<SignalArg>) -> PyResult<Option<i32>> {
+    match signal.map(SignalArg::number).transpose()? {
+        None | Some(0) => Ok(None),
+        Some(signal) => signal_from_raw(signal).map(Some),
+    }
+}
+
+/// Set the parent-death signal, and notify the [`set_trace_hook()`]
+fn do_set(signal: Option<i32>) -> PyResult<()> {
+    try_do_set(signal)?.map_err(os_error)
+}
+
+/// Like [`do_set()`], but return the errno if the OS rejected the signal, instead of raising it
+fn try_do_set(signal: Option<i32>) -> PyResult<Result<(), Errno>> {
+    Python::with_gil(|py| {
+        let hook = TRACE_HOOK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|hook| hook.clone_ref(py));
+        let Some(hook) = hook else {
+            if let Err(err) = sys_set(py, signal)? {
+                return Ok(Err(err));
+            }
+            signal_changed(signal);
+            return Ok(Ok(()));
+        };
+
+        let old = do_get(py)?;
+        if let Err(err) = sys_set(py, signal)? {
+            return Ok(Err(err));
+        }
+        signal_changed(signal);
+        let action = if signal.is_some() { "set" } else { "clear" };
+        let new = signal
+            .map(|signal| WrappedSignal::from_number(py, signal))
+            .transpose()?;
+        let _ = hook.call1(py, (action, old, new))?;
+        Ok(Ok(()))
+    })
+}
+
+/// Invalidate [`GET_CACHE`], and remember the state for `set(strict=True)` and [`owning_tid()`]
+fn signal_changed(signal: Option<i32>) {
+    let generation = SET_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+    SIGNAL_WAS_SET.store(signal.is_some(), Ordering::Release);
+    #[cfg(linux_kernel)]
+    {
+        let mut owners = signal_owners()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let _ = match signal {
+            Some(_) => owners.insert(gettid(), (getpid(), generation)),
+            None => owners.remove(&gettid()),
+        };
+    }
+    #[cfg(not(linux_kernel))]
+    let _ = (signal, generation);
+}
+
+#[cfg(linux_kernel)]
+fn do_get(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    match do_get_raw(py)? {
+        Some(signal) => Ok(Some(WrappedSignal::from_number(py, signal)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(linux_kernel)]
+fn do_get_raw(py: Python<'_>) -> PyResult<Option<i32>> {
+    // `rustix` returns `None` for real-time signals, too
+    if REAL_TIME_SIGNAL_SET.load(Ordering::Acquire) {
+        return match prctl_get_pdeathsig(py)? {
+            Ok(0) => Ok(None),
+            Ok(signal) => Ok(Some(signal)),
+            Err(err) => Err(os_error(err)),
+        };
+    }
+    match py.allow_threads(parent_process_death_signal) {
+        Ok(signal) => Ok(signal.map(|signal| signal as i32)),
+        Err(err) => Err(os_error(err)),
+    }
+}
+
+/// [`do_get_raw()`] without releasing the GIL, for [`get_raw()`] and [`is_set()`]
+#[cfg(linux_kernel)]
+fn peek_raw(py: Python<'_>) -> PyResult<Option<i32>> {
+    if REAL_TIME_SIGNAL_SET.load(Ordering::Acquire) {
+        return do_get_raw(py);
+    }
+    match parent_process_death_signal() {
+        Ok(signal) => Ok(signal.map(|signal| signal as i32)),
+        Err(err) => Err(os_error(err)),
+    }
+}
+
+/// Whether this module ever set a real-time signal
+///
+/// Reading the signal through `ctypes` is several times slower than through `rustix`, so
+/// [`do_get_raw()`] only does it if a real-time signal can be set at all.
+/// The flag is never cleared, because the setting is per thread.
+#[cfg(linux_kernel)]
+static REAL_TIME_SIGNAL_SET: AtomicBool = AtomicBool::new(false);
+
+#[cfg(linux_kernel)]
+fn sys_set(py: Python<'_>, signal: Option<i32>) -> PyResult<Result<(), Errno>> {
+    match signal {
+        Some(signal) if Signal::from_raw(signal).is_none() => {
+            REAL_TIME_SIGNAL_SET.store(true, Ordering::Release);
+            prctl_set_pdeathsig(py, signal)
+        },
+        signal => {
+            let signal = signal.and_then(Signal::from_raw);
+            Ok(py.allow_threads(|| set_parent_process_death_signal(signal)))
+        },
+    }
+}
+
+/// Map an errno to the most specific Python exception
+///
+/// The exception is always an `OSError` or one of its subclasses, even for `EINVAL`, so that
+/// `except OSError` catches every error reported by the OS.
+/// The exception is constructed like `OSError(errno, os.strerror(errno))`, so that Python
+/// populates the `errno` and `strerror` attributes, same as for errors raised by the `os` module.
+/// Thus `err.errno == err.args[0]` is always the numeric errno, c.f. `last_errno()` on Linux.
+#[cold]
+fn os_error(err: Errno) -> PyErr {
+    let args = OsErrorArgs(err.raw_os_error());
+    match err {
+        #[cfg(unix)]
+        Errno::PERM | Errno::ACCESS => PyPermissionError::new_err(args),
+        #[cfg(unix)]
+        Errno::SRCH => PyProcessLookupError::new_err(args),
+        _ => PyOSError::new_err(args),
+    }
+}
+
+/// Lazily built `(errno, strerror)` arguments of an `OSError`
+struct OsErrorArgs(i32);
+
+impl PyErrArguments for OsErrorArgs {
+    fn arguments(self, py: Python<'_>) -> PyObject {
+        // Use Python's own message, so it does not contain Rust's " (os error N)" suffix.
+        let strerror = py
+            .import_bound("os")
+            .and_then(|os| os.call_method1("strerror", (self.0,)))
+            .map_or_else(
+                |_| format!("os error {}", self.0).into_py(py),
+                Bound::unbind,
+            );
+        (self.0, strerror).into_py(py)
+    }
+}
+
+// `rustix` only knows about the named signals, so real-time signals need `prctl()` itself.
+// `ctypes` calls it for us, so we don't need any `unsafe` code.
+
+#[cfg(linux_kernel)]
+fn prctl_get_pdeathsig(py: Python<'_>) -> PyResult<Result<i32, Errno>> {
+    let prctl = Prctl::get(py)?;
+    let signal = prctl.c_int.bind(py).call0()?;
+    match prctl
+        .get_pdeathsig
+        .bind(py)
+        .call1((libc::PR_GET_PDEATHSIG, &signal))?
+        .extract()?
+    {
+        0 => Ok(Ok(signal.getattr(intern!(py, "value"))?.extract()?)),
+        _ => Ok(Err(last_errno(py)?)),
+    }
+}
+
+#[cfg(linux_kernel)]
+fn prctl_set_pdeathsig(py: Python<'_>, signal: i32) -> PyResult<Result<(), Errno>> {
+    match Prctl::get(py)?
+        .set_pdeathsig
+        .bind(py)
+        .call1((libc::PR_SET_PDEATHSIG, signal))?
+        .extract()?
+    {
+        0 => Ok(Ok(())),
+        _ => Ok(Err(last_errno(py)?)),
+    }
+}
+
+/// `prctl()` of the C library, loaded with `ctypes`, which preserves `errno`
+///
+/// `prctl()` is variadic, so there is one function object per prototype. The objects are looked
+/// up only once, because `get()` is called often to test if a signal is set at all.
+#[cfg(linux_kernel)]
+struct Prctl {
+    /// `prctl(int, int *)`
+    get_pdeathsig: PyObject,
+    /// `prctl(int, unsigned long)`
+    set_pdeathsig: PyObject,
+    /// `ctypes.c_int`
+    c_int: PyObject,
+}
+
+#[cfg(linux_kernel)]
+impl Prctl {
+    fn get(py: Python<'_>) -> PyResult<&Self> {
+        static PRCTL: GILOnceCell<Prctl> = GILOnceCell::new();
+        PRCTL.get_or_try_init(py, || {
+            let ctypes = py.import_bound("ctypes")?;
+            let kwargs = [("use_errno", true)].into_py_dict_bound(py);
+            let libc = ctypes.getattr("CDLL")?.call((py.None(),), Some(&kwargs))?;
+            let c_int = ctypes.getattr("c_int")?;
+            let function = |args: Bound<'_, PyAny>| -> PyResult<PyObject> {
+                // `CDLL.__getitem__()` returns a new object every time, unlike `getattr()`
+                let function = libc.get_item("prctl")?;
+                function.setattr("argtypes", args)?;
+                Ok(function.unbind())
+            };
+            Ok(Prctl {
+                get_pdeathsig: function(
+                    [c_int.clone(), ctypes.call_method1("POINTER", (&c_int,))?]
+                        .into_py(py)
+                        .into_bound(py),
+                )?,
+                set_pdeathsig: function(
+                    [c_int.clone(), ctypes.getattr("c_ulong")?]
+                        .into_py(py)
+                        .into_bound(py),
+                )?,
+                c_int: c_int.unbind(),
+            })
+        })
+    }
+}
+
+/// The `errno` of the failed `prctl()` call
+///
+/// If the C library did not set `errno`, which should not happen, then `EINVAL` is substituted,
+/// so that the raised exception always has a numeric `errno`.
+#[cfg(linux_kernel)]
+#[cold]
+fn last_errno(py: Python<'_>) -> PyResult<Errno> {
+    let errno: i32 = py
+        .import_bound("ctypes")?
+        .call_method0("get_errno")?
+        .extract()?;
+    Ok(match errno {
+        0 => Errno::INVAL,
+        errno => Errno::from_raw_os_error(errno),
+    })
+}
+
+#[cfg(target_os = "freebsd")]
+fn do_get(py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    match py.allow_threads(parent_process_death_signal) {
+        Ok(Some(signal)) => Ok(Some(WrappedSignal::from_signal(py, signal)?)),
+        Ok(None) => Ok(None),
+        Err(err) => Err(os_error(err)),
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn do_get_raw(py: Python<'_>) -> PyResult<Option<i32>> {
+    match py.allow_threads(parent_process_death_signal) {
+        Ok(signal) => Ok(signal.map(|signal| signal as i32)),
+        Err(err) => Err(os_error(err)),
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn peek_raw(_py: Python<'_>) -> PyResult<Option<i32>> {
+    match parent_process_death_signal() {
+        Ok(signal) => Ok(signal.map(|signal| signal as i32)),
+        Err(err) => Err(os_error(err)),
+    }
+}
+
+#[cfg(target_os = "freebsd")]
+fn sys_set(py: Python<'_>, signal: Option<i32>) -> PyResult<Result<(), Errno>> {
+    let signal = match signal {
+        Some(signal) => Some(Signal::from_raw(signal).ok_or_else(|| illegal_signal(signal))?),
+        None => None,
+    };
+    Ok(py.allow_threads(|| set_parent_process_death_signal(signal)))
+}
+
+#[cfg(not(pdeathsig))]
+fn do_get(_py: Python<'_>) -> PyResult<Option<Py<WrappedSignal>>> {
+    Err(unsupported())
+}
+
+#[cfg(not(pdeathsig))]
+fn do_get_raw(_py: Python<'_>) -> PyResult<Option<i32>> {
+    Err(unsupported())
+}
+
+#[cfg(not(pdeathsig))]
+fn peek_raw(_py: Python<'_>) -> PyResult<Option<i32>> {
+    Err(unsupported())
+}
+
+#[cfg(not(pdeathsig))]
+fn sys_set(_py: Python<'_>, _signal: Option<i32>) -> PyResult<Result<(), Errno>> {
+    Err(unsupported())
+}
+
+#[cfg(not(pdeathsig))]
+#[cold]
+fn unsupported() -> PyErr {
+    PyNotImplementedError::new_err(("parent-death signal is not supported on this platform",))
+}
+
+fn signal_from_raw(signal: i32) -> PyResult<i32> {
+    if Signal::from_raw(signal).is_some() || is_realtime(signal) {
+        Ok(signal)
+    } else {
+        Err(illegal_signal(signal))
+    }
+}
+
+#[cold]
+fn illegal_signal(signal: i32) -> PyErr {
+    PyValueError::new_err((format!("Illegal signal number {signal}"),))
+}
+
+#[cold]
+fn unknown_signal_name(name: &str) -> PyErr {
+    PyValueError::new_err((format!("Unknown signal name {name:?}"),))
+}
+
+fn signal_from_name(name: &str) -> Option<i32> {
+    let name = match name.get(..3) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("SIG") => &name[3..],
+        _ => name,
+    };
+    (1..nsig())
+        .filter(|&signal| Signal::from_raw(signal).is_some() || is_realtime(signal))
+        .find(|&signal| WrappedSignal(signal).abbrev().eq_ignore_ascii_case(name))
+        .or_else(|| realtime_from_name(name))
+        .or_else(|| {
+            SIGNAL_ALIASES
+                .iter()
+                .find(|(alias, _)| alias[3..].eq_ignore_ascii_case(name))
+                .map(|&(_, signal)| signal as i32)
+        })
+}
+
+/// Historical names of signals: accepted as input, but never returned by [`WrappedSignal::name`]
+const SIGNAL_ALIASES: &[(&str, Signal)] = &[
+    #[cfg(unix)]
+    ("SIGIOT", Signal::Abort),
+    #[cfg(linux_kernel)]
+    ("SIGCLD", Signal::Child),
+    #[cfg(linux_kernel)]
+    ("SIGPOLL", Signal::Io),
+    #[cfg(linux_kernel)]
+    ("SIGUNUSED", Signal::Sys),
+];
+
+#[cfg(linux_kernel)]
+fn is_realtime(signal: i32) -> bool {
+    (libc::SIGRTMIN()..=libc::SIGRTMAX()).contains(&signal)
+}
+
+#[cfg(not(linux_kernel))]
+fn is_realtime(_signal: i32) -> bool {
+    false
+}
+
+/// One more than the highest signal number, like `signal.NSIG`
+///
+/// On Linux the range of the real-time signals is only known at runtime.
+#[cfg(linux_kernel)]
+fn nsig() -> i32 {
+    libc::SIGRTMAX() + 1
+}
+
+#[cfg(not(linux_kernel))]
+fn nsig() -> i32 {
+    SIGNAL_COUNT as i32
+}
+
+/// Whether `signal` is `SIGRTMIN` or `SIGRTMAX`
+#[cfg(linux_kernel)]
+fn is_realtime_limit(signal: i32) -> bool {
+    signal == libc::SIGRTMIN() || signal == libc::SIGRTMAX()
+}
+
+#[cfg(not(linux_kernel))]
+fn is_realtime_limit(_signal: i32) -> bool {
+    false
+}
+
+/// `"SIGRTMIN+n"` for every real-time signal but `SIGRTMAX`
+#[cfg(linux_kernel)]
+fn realtime_name(signal: i32) -> String {
+    match signal - libc::SIGRTMIN() {
+        0 => "SIGRTMIN".to_owned(),
+        _ if signal == libc::SIGRTMAX() => "SIGRTMAX".to_owned(),
+        offset => format!("SIGRTMIN+{offset}"),
+    }
+}
+
+#[cfg(not(linux_kernel))]
+fn realtime_name(signal: i32) -> String {
+    format!("SIG{signal}")
+}
+
+/// Parse `"RTMIN+n"` for any offset, e.g. `"RTMIN+0"`, which [`realtime_name()`] calls `"SIGRTMIN"`
+#[cfg(linux_kernel)]
+fn realtime_from_name(name: &str) -> Option<i32> {
+    let offset = match name.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("RTMIN+") => &name[6..],
+        _ => return None,
+    };
+    if offset.is_empty() || !offset.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let signal = libc::SIGRTMIN().checked_add(offset.parse().ok()?)?;
+    is_realtime(signal).then_some(signal)
+}
+
+#[cfg(not(linux_kernel))]
+fn realtime_from_name(_name: &str) -> Option<i32> {
+    None
+}
+
+impl WrappedSignal {
+    fn from_signal(py: Python<'_>, signal: Signal) -> PyResult<Py<Self>> {
+        Self::from_number(py, signal as i32)
+    }
+
+    /// `signal` must be a valid signal number
+    fn from_number(py: Python<'_>, signal: i32) -> PyResult<Py<Self>> {
+        // Only the signals that are actually used get allocated. The common signals have
+        // a dedicated slot, so looking them up does not need to lock or to hash.
+        static SIGNALS: [GILOnceCell<Py<WrappedSignal>>; SIGNAL_COUNT] =
+            [const { GILOnceCell::new() }; SIGNAL_COUNT];
+        static OTHER_SIGNALS: OnceLock<Mutex<HashMap<i32, Py<WrappedSignal>>>> = OnceLock::new();
+
+        if let Some(cell) = usize::try_from(signal).ok().and_then(|i| SIGNALS.get(i)) {
+            return cell
+                .get_or_try_init(py, || Py::new(py, WrappedSignal(signal)))
+                .map(|cached| cached.clone_ref(py));
+        }
+
+        // don't panic if the platform has more signals than we expected
+        let other_signals = OTHER_SIGNALS.get_or_init(Mutex::default);
+        if let Some(cached) = other_signals
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&signal)
+        {
+            return Ok(cached.clone_ref(py));
+        }
+        // Don't hold the lock while allocating: this may run the GC, and thus arbitrary Python
+        // code that could call back into this function. If another call won the race, then its
+        // object is used.
+        let new = Py::new(py, WrappedSignal(signal))?;
+        Ok(other_signals
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(signal)
+            .or_insert(new)
+            .clone_ref(py))
+    }
+
+    /// A summary of the signal and its default action, used as `__doc__` of the instance
+    fn doc(&self, py: Python<'_>) -> PyResult<String> {
+        let action = match self.default_action() {
+            "core" => "terminate the process and dump core",
+            "ignore" => "ignore the signal",
+            "stop" => "stop the process",
+            "cont" => "continue the process if it is stopped",
+            _ => "terminate the process",
+        };
+        Ok(format!(
+            "{} ({}): {}\n\nDefault action: {action}.",
+            self.name(),
+            self.0,
+            self.description(py)?,
+        ))
+    }
+
+    /// The signal `offset` numbers after this one
+    fn offset_by(&self, py: Python<'_>, offset: i64) -> PyResult<PyObject> {
+        let signal = i64::from(self.0) + offset;
+        let signal = i32::try_from(signal).unwrap_or(i32::MAX);
+        Ok(WrappedSignal::from_number(py, signal_from_raw(signal)?)?.into_py(py))
+    }
+
+    /// The named signal, or `None` for real-time signals
+    fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.0)
+    }
+
+    /// The mnemonic of a named signal, or `None` for real-time signals
+    ///
+    /// The match is not exhaustive on purpose: signals that only exist on other platforms
+    /// get a numeric fallback name instead of breaking the build.
+    #[cfg(unix)]
+    fn named(&self) -> Option<&'static str> {
+        Some(match self.signal()? {
+            Signal::Hup => "SIGHUP",
+            Signal::Int => "SIGINT",
+            Signal::Quit => "SIGQUIT",
+            Signal::Ill => "SIGILL",
+            Signal::Trap => "SIGTRAP",
+            Signal::Abort => "SIGABRT",
+            Signal::Bus => "SIGBUS",
+            Signal::Fpe => "SIGFPE",
+            Signal::Kill => "SIGKILL",
+            Signal::Usr1 => "SIGUSR1",
+            Signal::Segv => "SIGSEGV",
+            Signal::Usr2 => "SIGUSR2",
+            Signal::Pipe => "SIGPIPE",
+            Signal::Alarm => "SIGALRM",
+            Signal::Term => "SIGTERM",
+            #[cfg(has_sigstkflt)]
+            Signal::Stkflt => "SIGSTKFLT",
+            Signal::Child => "SIGCHLD",
+            Signal::Cont => "SIGCONT",
+            Signal::Stop => "SIGSTOP",
+            Signal::Tstp => "SIGTSTP",
+            Signal::Ttin => "SIGTTIN",
+            Signal::Ttou => "SIGTTOU",
+            Signal::Urg => "SIGURG",
+            Signal::Xcpu => "SIGXCPU",
+            Signal::Xfsz => "SIGXFSZ",
+            Signal::Vtalarm => "SIGVTALRM",
+            Signal::Prof => "SIGPROF",
+            Signal::Winch => "SIGWINCH",
+            Signal::Io => "SIGIO",
+            #[cfg(linux_kernel)]
+            Signal::Power => "SIGPWR",
+            Signal::Sys => "SIGSYS",
+            #[cfg(has_sigemt)]
+            Signal::Emt => "SIGEMT",
+            #[cfg(bsd)]
+            Signal::Info => "SIGINFO",
+            #[cfg(target_os = "freebsd")]
+            Signal::Thr => "SIGTHR",
+            #[cfg(target_os = "freebsd")]
+            Signal::Librt => "SIGLIBRT",
+            #[allow(unreachable_patterns)]
+            _ => return None,
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn named(&self) -> Option<&'static str> {
+        Some(match self.signal()? {
+            Signal::Int => "SIGINT",
+            Signal::Ill => "SIGILL",
+            Signal::Fpe => "SIGFPE",
+            Signal::Segv => "SIGSEGV",
+            Signal::Term => "SIGTERM",
+            Signal::Abort => "SIGABRT",
+        })
+    }
+}
+
+/// Stands in for `rustix::process::Signal` on platforms without POSIX signals, e.g. Windows
+///
+/// These are the signals of the C runtime, with its numbering.
+#[cfg(not(unix))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+enum Signal {
+    Int = 2,
+    Ill = 4,
+    Fpe = 8,
+    Segv = 11,
+    Term = 15,
+    Abort = 22,
+}
+
+#[cfg(not(unix))]
+impl Signal {
+    fn from_raw(signal: i32) -> Option<Self> {
+        match signal {
+            2 => Some(Self::Int),
+            4 => Some(Self::Ill),
+            8 => Some(Self::Fpe),
+            11 => Some(Self::Segv),
+            15 => Some(Self::Term),
+            22 => Some(Self::Abort),
+            _ => None,
+        }
+    }
+}
+
+/// Stands in for `OwnedFd` on platforms without file descriptors, where no pidfd can be opened
+#[cfg(not(unix))]
+#[derive(Debug)]
+enum OwnedFd {}
+
+#[cfg(not(unix))]
+impl OwnedFd {
+    fn as_raw_fd(&self) -> i32 {
+        match *self {}
+    }
+
+    fn into_raw_fd(self) -> i32 {
+        match self {}
+    }
+}
+
+// Linux has up to 64 signals, including the real-time signals, except on MIPS, which has 127.
+// The numbers are taken from `libc` for the target architecture, e.g. SIGCHLD is 18 on MIPS and
+// 20 on SPARC. Higher numbers, if there are any, are valid, too, c.f. `nsig()`.
+#[cfg(all(linux_kernel, not(linux_mips)))]
+const SIGNAL_COUNT: usize = 65;
+
+#[cfg(linux_mips)]
+const SIGNAL_COUNT: usize = 128;
+
+#[cfg(not(pdeathsig))]
 const SIGNAL_COUNT: usize = 32;
+
+// FreeBSD has `SIGTHR = 32` and `SIGLIBRT = 33`
+#[cfg(target_os = "freebsd")]
+const SIGNAL_COUNT: usize = 34;
@@ -1,5 +1,6 @@
 from ._pdeathsignal import *
-from ._pdeathsignal import __doc__
+from ._pdeathsignal import __all__, __doc__, __version__
+from ._pdeathsignal import ForkHook, Guard, ParentDeathHandler, Scoped
 
 from pdeathsignal import Signal
 
@@ -1,26 +1,360 @@
 """Set or get the parent-death signal number of the calling process"""
 
+import asyncio
+import signal
+import sys
+from collections.abc import Callable, Iterable, Iterator
+from types import FrameType
+from typing import Literal, final, overload
+
+# the handles returned by e.g. `scoped()` are available, but not exported
+__all__ = [
+    "Signal",
+    "SignalSet",
+    "Policy",
+    "DisabledType",
+    "DISABLED",
+    "NSIG",
+    "blocked_signals",
+    "can_set",
+    "clear",
+    "ensure_deliverable",
+    "get",
+    "get_raw",
+    "getpgid",
+    "getppid",
+    "getsid",
+    "guard",
+    "is_set",
+    "keep_across_fork",
+    "on_parent_death",
+    "owning_tid",
+    "parent_death_fd",
+    "parent_is_alive",
+    "raise_configured",
+    "recommended_signals",
+    "reset",
+    "scoped",
+    "self_test",
+    "set",
+    "set_and_get",
+    "set_first_supported",
+    "set_from_env",
+    "set_if_orphaned",
+    "set_trace_hook",
+    "signals",
+    "supported",
+    "suspended",
+    "try_set",
+    "wait_parent_exit",
+    "wait_parent_exit_blocking",
+    "SIGINT",
+    "SIGILL",
+    "SIGABRT",
+    "SIGFPE",
+    "SIGSEGV",
+    "SIGTERM",
+]
+if sys.platform != "win32":
+    __all__ += [
+        "SIGHUP",
+        "SIGQUIT",
+        "SIGTRAP",
+        "SIGBUS",
+        "SIGKILL",
+        "SIGUSR1",
+        "SIGUSR2",
+        "SIGPIPE",
+        "SIGALRM",
+        "SIGCHLD",
+        "SIGCONT",
+        "SIGSTOP",
+        "SIGTSTP",
+        "SIGTTIN",
+        "SIGTTOU",
+        "SIGURG",
+        "SIGXCPU",
+        "SIGXFSZ",
+        "SIGVTALRM",
+        "SIGPROF",
+        "SIGWINCH",
+        "SIGIO",
+        "SIGSYS",
+    ]
+if sys.platform == "linux" or sys.platform == "android":
+    __all__ += ["SIGSTKFLT", "SIGPWR"]
+if (
+    sys.platform == "darwin"
+    or sys.platform.startswith("freebsd")
+    or sys.platform.startswith("dragonfly")
+    or sys.platform.startswith("netbsd")
+    or sys.platform.startswith("openbsd")
+):
+    __all__ += ["SIGEMT", "SIGINFO"]
+if sys.platform.startswith("freebsd"):
+    __all__ += ["SIGTHR", "SIGLIBRT"]
+if sys.platform != "win32":
+    __all__ += ["SIGIOT"]
+if sys.platform == "linux" or sys.platform == "android":
+    __all__ += ["SIGCLD", "SIGPOLL", "SIGUNUSED", "SIGRTMIN", "SIGRTMAX"]
+    __all__ += ["PR_SET_PDEATHSIG", "PR_GET_PDEATHSIG"]
+
+__version__: str
+
+@final
 class Signal:
-    """A signal number"""
+    """A signal number
 
-    def __init__(self, signal: Signal | int) -> Signal:
-        """Convert an integer to a Signal"""
-    SIGHUP: Signal = ...
+    Like enum members, signals are singletons, so `Signal(15) is Signal.SIGTERM`.
+    `signal.value`, `int(signal)` and `hash(signal)` are the signal number, like for `signal.Signals`.
+    A signal does not compare equal to its mnemonic, because the hashes would differ.
+    Use `Signal.from_name(name) == signal` instead.
+    `Signal(0)` raises `ValueError`: `set()` treats 0 as "no signal", but 0 is no signal itself.
+    `bool` is rejected with a `TypeError` everywhere, although it is a subclass of `int`.
+    The class is final, because instances of a subclass would bypass the cache of singletons."""
+
+    def __new__(cls, value: Signal | int | str) -> Signal:
+        """Convert an integer or a mnemonic to a Signal, like `Signal.parse()`
+
+        The cached singleton is returned, so `Signal(signal) is signal` for every Signal."""
+    def __index__(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __bool__(self) -> Literal[True]:
+        """Always `True`: there is no signal 0, so every signal is truthy, and `if get():` is the same
+        as `if get() is not None:`"""
+    def __add__(self, other: int) -> Signal:
+        """`signal + n` is the signal `n` numbers after `signal`, e.g. `Signal.SIGRTMIN + 3`
+
+        The result is a `Signal`, not an `int`, so a `ValueError` is raised if there is no such
+        signal. Adding two signals is a `TypeError`."""
+
+    def __radd__(self, other: int) -> Signal: ...
+    @overload
+    def __sub__(self, other: Signal) -> int:
+        """`signal - n` is the signal `n` numbers before `signal`, and `signal - other` is the
+        distance between two signals as an `int`"""
+
+    @overload
+    def __sub__(self, other: int) -> Signal: ...
+    def __pos__(self) -> int: ...
+    def __neg__(self) -> int: ...
+    def __hash__(self) -> int:
+        """Same as `hash(int(signal))`, so signals and numbers are interchangeable as dict keys"""
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: Signal | int) -> bool:
+        """Signals are ordered by their number, so real-time signals sort after the classic ones"""
+    def __le__(self, other: Signal | int) -> bool: ...
+    def __gt__(self, other: Signal | int) -> bool: ...
+    def __ge__(self, other: Signal | int) -> bool: ...
+    def __bytes__(self) -> bytes:
+        """The signal number as a single byte"""
+    def __copy__(self) -> Signal: ...
+    def __deepcopy__(self, memo: object, /) -> Signal: ...
+    def __getstate__(self) -> int: ...
+    def __setstate__(self, state: int, /) -> None: ...
     SIGINT: Signal = ...
-    SIGQUIT: Signal = ...
     SIGILL: Signal = ...
-    SIGTRAP: Signal = ...
     SIGABRT: Signal = ...
-    SIGBUS: Signal = ...
     SIGFPE: Signal = ...
+    SIGSEGV: Signal = ...
+    SIGTERM: Signal = ...
+    if sys.platform != "win32":
+        SIGHUP: Signal = ...
+        SIGQUIT: Signal = ...
+        SIGTRAP: Signal = ...
+        SIGBUS: Signal = ...
+        SIGKILL: Signal = ...
+        SIGUSR1: Signal = ...
+        SIGUSR2: Signal = ...
+        SIGPIPE: Signal = ...
+        SIGALRM: Signal = ...
+        SIGCHLD: Signal = ...
+        SIGCONT: Signal = ...
+        SIGSTOP: Signal = ...
+        SIGTSTP: Signal = ...
+        SIGTTIN: Signal = ...
+        SIGTTOU: Signal = ...
+        SIGURG: Signal = ...
+        SIGXCPU: Signal = ...
+        SIGXFSZ: Signal = ...
+        SIGVTALRM: Signal = ...
+        SIGPROF: Signal = ...
+        SIGWINCH: Signal = ...
+        SIGIO: Signal = ...
+        SIGSYS: Signal = ...
+    if sys.platform == "linux" or sys.platform == "android":
+        SIGSTKFLT: Signal = ...
+        SIGPWR: Signal = ...
+    if (
+        sys.platform == "darwin"
+        or sys.platform.startswith("freebsd")
+        or sys.platform.startswith("dragonfly")
+        or sys.platform.startswith("netbsd")
+        or sys.platform.startswith("openbsd")
+    ):
+        SIGEMT: Signal = ...
+        SIGINFO: Signal = ...
+    if sys.platform.startswith("freebsd"):
+        SIGTHR: Signal = ...
+        SIGLIBRT: Signal = ...
+    if sys.platform != "win32":
+        JOB_CONTROL: SignalSet
+        """The job-control signals: SIGCONT, SIGSTOP, SIGTSTP, SIGTTIN and SIGTTOU"""
+        TERMINAL: SignalSet
+        """The signals sent by a terminal: SIGHUP, SIGINT, SIGQUIT and SIGWINCH"""
+    CORE_DUMPING: SignalSet
+    """The signals that dump core by default, i.e. their `default_action` is `"core"`"""
+
+    # historical aliases
+    if sys.platform != "win32":
+        SIGIOT: Signal = ...
+    if sys.platform == "linux" or sys.platform == "android":
+        SIGCLD: Signal = ...
+        SIGPOLL: Signal = ...
+        SIGUNUSED: Signal = ...
+        SIGRTMIN: Signal = ...
+        SIGRTMAX: Signal = ...
+
+        @staticmethod
+        def rtmin_plus(offset: int) -> Signal:
+            """The real-time signal `SIGRTMIN + offset`"""
+
+    @property
+    def raw(self) -> int:
+        """The signal number as a plain `int`, same as `int(signal)`"""
+
+    @property
+    def value(self) -> int:
+        """The signal number, like `signal.Signals.SIGTERM.value`"""
+
+    __match_args__: tuple[Literal["value"]]
+
+    @property
+    def name(self) -> str:
+        """The mnemonic of the signal, e.g. `"SIGTERM"` or `"SIGRTMIN+3"`"""
+
+    def compact_repr(self) -> str:
+        """The mnemonic of the signal, e.g. `"SIGTERM"`, for log lines where `repr()` is too verbose"""
+
+    @property
+    def abbrev(self) -> str:
+        """The mnemonic of the signal without its `SIG` prefix, e.g. `"TERM"`"""
+
+    @property
+    def description(self) -> str:
+        """A human-readable description of the signal, e.g. `"Terminated"`
+
+        The description is provided by `strsignal(3)`. The mnemonic is used as fallback."""
+
+    @property
+    def __doc__(self) -> str:  # type: ignore[override]
+        """A summary of the signal and its default action, e.g. for `help(Signal.SIGTERM)`"""
+
+    @property
+    def is_catchable(self) -> bool:
+        """Whether the signal can be caught, blocked or ignored, i.e. it is neither SIGKILL nor SIGSTOP"""
+
+    @property
+    def is_stop(self) -> bool:
+        """Whether the signal is a job-control signal that stops the process"""
+
+    @property
+    def default_action(self) -> Literal["term", "core", "ignore", "stop", "cont"]:
+        """The default action of the signal: `"term"`, `"core"`, `"ignore"`, `"stop"` or `"cont"`"""
+
+    @staticmethod
+    def parse(value: Signal | int | str) -> Signal:
+        """Convert a Signal, a signal number, a mnemonic, or a decimal string like `"15"` to a Signal"""
+
+    @staticmethod
+    def strict(value: Signal | int) -> Signal:
+        """Like `Signal(value)`, but only accept a Signal or an integer, not a mnemonic
+
+        Unlike for `set()`, where 0 means "disable", 0 is rejected with a dedicated message."""
+
+    @classmethod
+    def __class_getitem__(cls, item: object, /) -> type[Signal]:
+        """`Signal[...]` is `Signal` itself, so that subscripted annotations don't fail at runtime"""
+
+    @staticmethod
+    def from_name(name: str) -> Signal:
+        """Look up a signal by its mnemonic, e.g. `"SIGTERM"` or `"term"`"""
+
+    @staticmethod
+    def from_c_int(value: int, /) -> Signal:
+        """Look up a signal by a number that came from C, e.g. `ctypes.c_int(15).value`
+
+        Like `Signal(int)`, but without checking for a Signal or a mnemonic first.
+        Raises `ValueError` if there is no such signal."""
+
+    @staticmethod
+    def from_index(index: int) -> Signal:
+        """Look up a signal by its number, raising `IndexError` if there is no such signal
+
+        Unlike `Signal(int)`, this only accepts plain integers, so it is a bit faster."""
+
+    @staticmethod
+    def from_bytes(data: bytes) -> Signal:
+        """The inverse of `bytes(signal)`: look up a signal by its number stored in a single byte"""
+
+    @staticmethod
+    def from_stdlib(signal: signal.Signals | int) -> Signal:
+        """Convert a `signal.Signals` (or any integer) to a Signal"""
+
+    def to_stdlib(self) -> signal.Signals:
+        """Convert the signal to the corresponding `signal.Signals` member
+
+        Signals implement `__index__`, so they can be used directly with e.g. `os.kill()` or
+        `signal.signal()`. Use this method for APIs that check `isinstance(sig, signal.Signals)`."""
+
+    @staticmethod
+    def from_mask(mask: int, /, *, strict: bool = False) -> list[Signal]:
+        """The signals in a mask like `SigBlk` in `/proc/<pid>/status`, where signal `n` is bit `n - 1`
+
+        Bits that are no valid signal are ignored, unless `strict` is true."""
+
+    @staticmethod
+    def to_mask(signals: Iterable[Signal | int]) -> int:
+        """The inverse of `from_mask()`"""
+
+    @staticmethod
+    def all() -> list[Signal]:
+        """All known signals in ascending numeric order"""
+
+    def __enter__(self) -> Signal:
+        """`with Signal.SIGTERM:` sets the parent-death signal for the duration of the block
+
+        Like `scoped()`, the previous setting is restored when the block is left."""
+
+    def __exit__(self, *args: object) -> bool: ...
+    def set(self) -> None:
+        """Set the parent-death signal number of the calling process"""
+
+    @staticmethod
+    def get() -> Signal | None:
+        """Get the parent-death signal number of the calling process"""
+
+    @staticmethod
+    def current() -> Signal | None:
+        """The currently configured parent-death signal, or `None`, same as `get()`"""
+
+SIGINT: Signal = ...
+SIGILL: Signal = ...
+SIGABRT: Signal = ...
+SIGFPE: Signal = ...
+SIGSEGV: Signal = ...
+SIGTERM: Signal = ...
+if sys.platform != "win32":
+    SIGHUP: Signal = ...
+    SIGQUIT: Signal = ...
+    SIGTRAP: Signal = ...
+    SIGBUS: Signal = ...
     SIGKILL: Signal = ...
     SIGUSR1: Signal = ...
-    SIGSEGV: Signal = ...
     SIGUSR2: Signal = ...
     SIGPIPE: Signal = ...
     SIGALRM: Signal = ...
-    SIGTERM: Signal = ...
-    SIGSTKFLT: Signal = ...
     SIGCHLD: Signal = ...
     SIGCONT: Signal = ...
     SIGSTOP: Signal = ...
@@ -34,18 +368,422 @@ class Signal:
     SIGPROF: Signal = ...
     SIGWINCH: Signal = ...
     SIGIO: Signal = ...
-    SIGPWR: Signal = ...
     SIGSYS: Signal = ...
+if sys.platform == "linux" or sys.platform == "android":
+    SIGSTKFLT: Signal = ...
+    SIGPWR: Signal = ...
+if (
+    sys.platform == "darwin"
+    or sys.platform.startswith("freebsd")
+    or sys.platform.startswith("dragonfly")
+    or sys.platform.startswith("netbsd")
+    or sys.platform.startswith("openbsd")
+):
+    SIGEMT: Signal = ...
+    SIGINFO: Signal = ...
+if sys.platform.startswith("freebsd"):
+    SIGTHR: Signal = ...
+    SIGLIBRT: Signal = ...
+if sys.platform != "win32":
+    SIGIOT: Signal = ...
+if sys.platform == "linux" or sys.platform == "android":
+    SIGCLD: Signal = ...
+    SIGPOLL: Signal = ...
+    SIGUNUSED: Signal = ...
+    SIGRTMIN: Signal = ...
+    SIGRTMAX: Signal = ...
+NSIG: int
+"""One more than the highest signal number, like `signal.NSIG`"""
 
-    def set(self):
-        """Set the parent-death signal number of the calling process"""
+@final
+class DisabledType:
+    """The type of `DISABLED`, which stands for "no parent-death signal"
+
+    `set()` and all other functions that take a signal accept `DISABLED` like `None`.
+    It is falsy, like `None`."""
+
+    def __bool__(self) -> Literal[False]: ...
+
+DISABLED: DisabledType
+"""A sentinel for "no parent-death signal", c.f. `get(use_sentinel=True)`"""
+if sys.platform == "linux" or sys.platform == "android":
+    PR_SET_PDEATHSIG: int
+    """The `prctl()` option to set the parent-death signal"""
+    PR_GET_PDEATHSIG: int
+    """The `prctl()` option to get the parent-death signal"""
+
+def keep_across_fork(signal: Signal | int | str | DisabledType | None, /) -> ForkHook:
+    """Set the parent-death signal in every child process forked from now on
 
+    The parent-death signal is cleared in the child of a `fork()`. This function registers
+    an `after_in_child` hook with `os.register_at_fork()` that calls `set(signal)` in the child.
+    The hook is best-effort: errors are reported through `sys.unraisablehook`, and it is not run
+    if the child was forked without Python's knowledge, e.g. by `subprocess`.
+
+    Hooks registered with `os.register_at_fork()` cannot be removed, so call `unregister()` on
+    the returned handle to make the hook a no-op."""
+
+class ForkHook:
+    """A handle returned by `keep_across_fork()`"""
+
+    def __call__(self) -> None: ...
+    def unregister(self) -> None:
+        """Stop setting the parent-death signal in forked children"""
+
+class SignalSet:
+    """An immutable set of signals"""
+
+    def __init__(self, signals: Iterable[Signal | int] = ..., /) -> None: ...
     @staticmethod
-    def get() -> Signal | None:
-        """Get the parent-death signal number of the calling process"""
+    def catchable() -> SignalSet:
+        """All signals that can be caught, i.e. all signals but SIGKILL and SIGSTOP"""
+
+    def __contains__(self, signal: object) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[Signal]: ...
+    def __and__(self, other: SignalSet) -> SignalSet: ...
+    def __or__(self, other: SignalSet) -> SignalSet: ...
+    def __sub__(self, other: SignalSet) -> SignalSet: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+def set(
+    signal: Signal | int | str | DisabledType | None,
+    /,
+    *,
+    require_main_thread: bool = False,
+    strict: bool = False,
+    quiet: bool = False,
+) -> None:
+    """Set the parent-death signal number of the calling process
+
+    Raises `NotImplementedError` if the platform is not `supported()`.
+
+    `signal` is converted like in `Signal.parse()`, so a mnemonic like `"TERM"` or a
+    decimal string like `"15"` is accepted, too. `None` and `0` clear the parent-death signal.
+
+    The parent-death signal is delivered when the thread that created the calling process exits,
+    not necessarily when the whole parent process exits. If `require_main_thread` is true, then
+    a `RuntimeError` is raised if `set()` is not called from the main thread.
+
+    If `strict` is true, then a `RuntimeError` is raised if a signal was already set by any function
+    of this module without an intervening `clear()`, to catch double-initialization bugs.
+    A child forked with `os.fork()` starts over, like the kernel clears the signal there.
+
+    A `RuntimeWarning` is emitted if the signal cannot be handled, i.e. for SIGKILL and SIGSTOP,
+    unless `quiet` is true."""
+
+@overload
+def get(
+    *,
+    cached: bool = False,
+    default: Signal | int | str | DisabledType | None = None,
+    required: bool = False,
+    use_sentinel: Literal[False] = False,
+) -> Signal | None:
+    """Get the parent-death signal number of the calling process
+
+    Raises `NotImplementedError` if the platform is not `supported()`.
+
+    If `cached` is true, then the result of a previous call is reused, unless the signal was
+    changed through this module in the meantime. Changes made by other means, e.g. by calling
+    `prctl()` directly or by forking, are not noticed.
+
+    On Linux a real-time signal is only reported if it was set through this module, e.g. not if it
+    was inherited across `exec()`, because reading it is several times slower than for the others.
+
+    If no signal is set, then `default` is returned, which is normalized like the argument
+    of `set()`, so `get(default="TERM")` returns `Signal.SIGTERM`.
+
+    If `required` is true, then a `LookupError` is raised instead of returning `None`.
+    If `use_sentinel` is true, then `DISABLED` is returned instead of `None`."""
+
+@overload
+def get(
+    *,
+    cached: bool = False,
+    default: Signal | int | str | DisabledType | None = None,
+    required: bool = False,
+    use_sentinel: Literal[True],
+) -> Signal | DisabledType: ...
+
+def get_raw() -> int | None:
+    """Get the parent-death signal number of the calling process as a plain `int`
+
+    This is the fast path of `get()`: no `Signal` is looked up or created, and the GIL
+    is not released for the system call, which takes less time than releasing the GIL.
+    Returns `None` if no parent-death signal is set."""
+
+def is_set() -> bool:
+    """Whether a parent-death signal is set for the calling process
+
+    This is cheaper than `get() is not None`, because no `Signal` is looked up,
+    and the GIL is not released, same as in `get_raw()`."""
+
+def set_first_supported(signals: Iterable[Signal | int | str], /) -> Signal:
+    """Set the first signal of `signals` that is accepted as parent-death signal, and return it
+
+    Invalid signals and signals that could not be set are skipped.
+    `signals` must not be a single `str`.
+    If no signal could be set, then the error of the last attempt is raised."""
+
+def try_set(signal: Signal | int | str | DisabledType | None, /) -> tuple[bool, int]:
+    """Like `set()`, but return `(True, 0)` on success, or `(False, errno)` if the OS rejected
+    the signal, instead of raising an `OSError`
+
+    Malformed arguments still raise `TypeError` or `ValueError`. This avoids the cost of
+    exceptions in tight loops."""
+
+class Policy:
+    """A reusable configuration: a list of fallback signals, and whether to warn about signals that
+    cannot be handled
+
+    `Policy([Signal.SIGTERM, Signal.SIGHUP]).apply()` is the same as
+    `set_first_supported([Signal.SIGTERM, Signal.SIGHUP])`, but the signals are validated once,
+    when the policy is created.
+
+    Invalid signals are skipped. If no signal is valid, then the error of the last one is raised."""
+
+    def __init__(
+        self, signals: Iterable[Signal | int | str], *, warn_noncatchable: bool = True
+    ) -> None: ...
+    @property
+    def signals(self) -> list[Signal]:
+        """The signals in the order they are tried"""
+
+    @property
+    def warn_noncatchable(self) -> bool:
+        """Whether `apply()` emits a `RuntimeWarning` for SIGKILL and SIGSTOP"""
+
+    def apply(self) -> Signal:
+        """Set the first signal that is accepted as parent-death signal, and return it
+
+        If no signal could be set, then the error of the last attempt is raised."""
+
+def set_from_env(var: str = "PDEATHSIG") -> bool:
+    """Set the parent-death signal to the value of the environment variable `var`
+
+    The value is parsed like `Signal.parse()`, i.e. it can be a mnemonic or a number.
+    Returns `False` if the variable is not set, and `True` if the signal was set."""
+
+def set_and_get(signal: Signal | int | str | DisabledType | None, /) -> Signal | None:
+    """Set the parent-death signal number of the calling process, and return the previous one
+
+    Like `signal.signal()` this returns the previous setting, or `None` if none was set."""
+
+def clear() -> None:
+    """Clear the parent-death signal of the calling process
+
+    This is the canonical way to disable the parent-death signal, equivalent to `set(None)`."""
+
+def reset() -> Signal | None:
+    """Clear the parent-death signal and all state kept by this module, and return the previous signal
+
+    This also forgets about signals set with `strict=True`, disables all `keep_across_fork()`
+    hooks, removes the `set_trace_hook()`, and forgets the previous settings of active
+    `with signal:` blocks.
+    Meant for test isolation, e.g. in a pytest fixture:
+
+    ```python
+    @pytest.fixture(autouse=True)
+    def pdeathsig():
+        yield
+        pdeathsignal.reset()
+    ```"""
+
+def can_set(signal: Signal | int | str | DisabledType | None, /) -> bool:
+    """Whether `signal` is accepted as parent-death signal
+
+    The signal is set tentatively, and the previous setting is restored afterwards.
+    The trace hook is not called for the tentative change.
+    Returns `False` for invalid signals, if setting the signal failed,
+    or if the platform is not `supported()`."""
+
+def self_test(timeout: float = 1.0) -> tuple[bool, str]:
+    """Check that the parent-death signal is actually delivered in this environment
+
+    A helper process is forked, which forks a child that sets SIGUSR1 as its parent-death signal.
+    Then the helper exits, and the child reports whether it received SIGUSR1 within `timeout`
+    seconds. Returns a tuple `(delivered, diagnostic_message)`."""
+
+def signals(
+    *,
+    catchable: bool | None = None,
+    default_action: Literal["term", "core", "ignore", "stop", "cont"] | None = None,
+) -> list[Signal]:
+    """All signals, including the real-time signals, that match the given criteria
+
+    `catchable` filters by `Signal.is_catchable`, `default_action` filters by
+    `Signal.default_action`. `None` means that the criterion is not checked."""
+
+def recommended_signals() -> list[Signal]:
+    """The signals that are sensible choices for a parent-death signal
+
+    These are all signals that can be caught, and terminate the process by default,
+    e.g. SIGTERM, SIGHUP or SIGUSR1, but not SIGCHLD, SIGCONT or SIGKILL."""
+
+def blocked_signals() -> list[Signal]:
+    """The signals that are currently blocked in the calling thread, sorted by their number
+
+    A blocked parent-death signal stays pending until it is unblocked, so this helps to find out
+    why the signal did not fire. Signals reserved by the C library are omitted."""
+
+def ensure_deliverable() -> bool:
+    """Unblock the configured parent-death signal in the calling thread, if it is blocked
+
+    Returns `True` if the signal was unblocked, and `False` if it was not blocked,
+    or if no parent-death signal is set."""
+
+def set_trace_hook(
+    hook: Callable[[Literal["set", "clear"], Signal | None, Signal | None], object] | None, /
+) -> None:
+    """Install a callback `hook(action, old, new)` that is called after every change of the
+    parent-death signal through this module
+
+    `action` is `"set"` or `"clear"`, `old` and `new` are the previous and new `Signal | None`.
+    Exceptions raised by the hook are propagated to the caller, after the signal was changed.
+    Passing `None` removes the hook."""
+
+def supported() -> bool:
+    """Whether the parent-death signal is supported on this platform
+
+    If not, then all functions that get or set the parent-death signal raise `NotImplementedError`."""
+
+def getppid() -> int:
+    """The process ID of the parent process, or 0 if there is none
+
+    The parent may die before the parent-death signal was set, so the signal would never fire.
+    To close this race, capture the parent's PID first, then call `set()`, and finally check
+    `parent_is_alive()` with the captured PID."""
+
+def getsid(pid: int = 0, /) -> int:
+    """The session ID of the process `pid`, or of the calling process if `pid` is 0
+
+    Like `os.getsid()`, but raises the same exceptions as the other functions of this module."""
+
+def getpgid(pid: int = 0, /) -> int:
+    """The process group ID of the process `pid`, or of the calling process if `pid` is 0
+
+    Like `os.getpgid()`, but raises the same exceptions as the other functions of this module."""
+
+def owning_tid() -> int | None:
+    """The thread ID of the thread that most recently set the parent-death signal through this
+    module, or `None` if every thread that set it cleared it again, or if it was not set in this
+    process
+
+    On Linux, the parent-death signal is an attribute of the thread that set it, not of the
+    whole process, so this helps to find out that e.g. a worker thread set it.
+    Always `None` on other platforms."""
+
+def parent_is_alive(original_ppid: int, /) -> bool:
+    """Whether the parent process is still `original_ppid`
+
+    If the parent died, then the calling process was reparented, typically to PID 1."""
+
+def set_if_orphaned(
+    signal: Signal | int | str | DisabledType | None, /, original_ppid: int | None = None
+) -> bool:
+    """Set the parent-death signal, and raise it right away if the parent already exited
+
+    This closes the race between forking and calling `set()`: the parent's PID is captured
+    before setting the signal, and checked again afterwards. If the calling process was
+    reparented in between, then `signal` is sent to the calling process immediately,
+    as if the parent had died after `set()`.
+
+    To cover the time between `fork()` and this call, too, pass the PID the parent had before
+    forking, i.e. its `os.getpid()`, as `original_ppid`.
+
+    Returns `True` if the parent was found to have exited."""
+
+def raise_configured() -> Signal | None:
+    """Send the configured parent-death signal to the calling process, as if the parent had died
+
+    This lets tests exercise the handling of the parent's death without killing the parent.
+    Returns the signal that was sent, or `None` if no parent-death signal is set."""
+
+def wait_parent_exit() -> asyncio.Future[None]:
+    """Wait until the parent process exits
+
+    Returns an `asyncio.Future` of the running event loop that is resolved when the parent exits.
+
+    Raises `NotImplementedError` if `pidfd_open(2)` is not supported."""
+
+def wait_parent_exit_blocking(timeout: float | None = None) -> bool:
+    """Block until the parent process exits, or until `timeout` seconds have passed
+
+    Returns `True` if the parent exited, and `False` if the timeout expired.
+    `timeout=None` or `float("inf")` waits indefinitely. This is the synchronous counterpart to
+    `wait_parent_exit()`, e.g. for shutdown sequences.
+
+    Raises `NotImplementedError` if `pidfd_open(2)` is not supported."""
+
+def parent_death_fd() -> int:
+    """Open a file descriptor that becomes readable when the parent process exits
+
+    The descriptor is a pidfd of the parent process, which can be registered with `select`,
+    `selectors` or an event loop. The caller owns the descriptor, and must close it with
+    `os.close()`.
+
+    Raises `ProcessLookupError` if the parent already exited,
+    and `NotImplementedError` if `pidfd_open(2)` is not supported."""
+
+def scoped(signal: Signal | int | str | DisabledType | None, /) -> Scoped:
+    """Set the parent-death signal number of the calling process for the duration of a `with` block
+
+    The previous setting is restored when the block is left, even if it raised an exception."""
+
+def suspended() -> Scoped:
+    """Clear the parent-death signal for the duration of a `with` block, e.g. for a critical section
+
+    This is the same as `scoped(None)`: the previous setting is restored when the block is left,
+    even if it raised an exception."""
+
+class Scoped:
+    """A context manager returned by `scoped()` and `suspended()`"""
+
+    def __enter__(self) -> Scoped: ...
+    def __exit__(self, *args: object) -> bool: ...
+
+def guard(
+    signal: Signal | int | str | DisabledType | None, /, original_ppid: int | None = None
+) -> Guard:
+    """Like `scoped()`, but send `signal` to the calling process when the block is left,
+    if the parent process exited in the meantime
+
+    The parent's PID is captured when the block is entered, or `original_ppid` is used if given,
+    cf. `set_if_orphaned()`. When the block is left, the previous parent-death signal is
+    restored in any case. Then, if the calling process was reparented, `signal` is sent to it,
+    even if the kernel already delivered the parent-death signal during the block.
+    If the parent is still alive, leaving the block only restores the previous setting."""
+
+class Guard:
+    """A context manager returned by `guard()`"""
+
+    def __enter__(self) -> Guard: ...
+    def __exit__(self, *args: object) -> bool: ...
+
+def on_parent_death(
+    callback: Callable[[int, FrameType | None], object],
+    signal: Signal | int | str = ...,
+) -> ParentDeathHandler:
+    """Set the parent-death signal, and install `callback` as the Python handler of that signal
+
+    This is a shorthand for `signal.signal(signal, callback)` followed by `set(signal)`.
+    If setting the parent-death signal fails, then the previous handler is reinstated.
+
+    Like any Python signal handler, `callback(signum, frame)` is always run in the main thread,
+    and `on_parent_death()` itself must be called from the main thread, too.
+
+    Call `remove()` on the returned handle to restore the previous handler and parent-death signal."""
+
+class ParentDeathHandler:
+    """A handle returned by `on_parent_death()`"""
+
+    @property
+    def signal(self) -> Signal:
+        """The signal the handler was installed for"""
 
-def set(signal: Signal | int):
-    """Set the parent-death signal number of the calling process"""
+    def remove(self) -> None:
+        """Restore the previous signal handler and parent-death signal
 
-def get() -> Signal | None:
-    """Get the parent-death signal number of the calling process"""
+        Calling this method more than once is a no-op."""
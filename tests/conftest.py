@@ -0,0 +1,9 @@
+import pytest
+
+import pdeathsignal
+
+
+@pytest.fixture(autouse=True)
+def pdeathsig():
+    yield
+    pdeathsignal.reset()
@@ -0,0 +1,172 @@
+"""Conversions between `Signal` and numbers, bytes and `signal.Signals`"""
+
+import os
+import signal
+import subprocess
+import sys
+
+import pytest
+
+from pdeathsignal import Signal, SignalSet
+
+
+def test_int():
+    assert int(Signal.SIGTERM) == 15
+    assert Signal.SIGTERM.value == 15
+    assert Signal.SIGTERM.raw == 15
+    assert [10, 11, 12, 13, 14, 15, 16][Signal.SIGINT] == 12
+    assert +Signal.SIGTERM == 15
+    assert -Signal.SIGTERM == -15
+
+
+def test_arithmetic():
+    assert Signal.SIGTERM - 1 == 14
+    assert isinstance(Signal.SIGTERM - 1, Signal)
+    assert Signal.SIGTERM - Signal.SIGINT == 13
+    assert type(Signal.SIGTERM - Signal.SIGINT) is int
+    with pytest.raises(TypeError):
+        _ = Signal.SIGTERM + Signal.SIGINT
+
+
+@pytest.mark.parametrize("value", [True, False])
+def test_bool_is_rejected(value):
+    with pytest.raises(TypeError, match="not bool"):
+        Signal(value)
+    with pytest.raises(TypeError, match="not bool"):
+        Signal.strict(value)
+
+
+@pytest.mark.parametrize(
+    "function",
+    [
+        Signal.from_stdlib,
+        Signal.from_c_int,
+        Signal.from_index,
+        Signal.from_mask,
+        lambda value: Signal.to_mask([value]),
+        lambda value: SignalSet([value]),
+    ],
+)
+def test_bool_is_rejected_by_number_conversions(function):
+    with pytest.raises(TypeError, match="not bool"):
+        function(True)
+
+
+def test_bool_is_rejected_by_arithmetic():
+    with pytest.raises(TypeError):
+        _ = Signal.SIGHUP + True
+    with pytest.raises(TypeError):
+        _ = True + Signal.SIGHUP
+    with pytest.raises(TypeError):
+        _ = Signal.SIGINT - True
+
+
+@pytest.mark.parametrize("value", [2.0, b"\x0f", object()])
+def test_other_types_are_rejected(value):
+    with pytest.raises(TypeError):
+        Signal(value)
+
+
+def test_strict():
+    assert Signal.strict(15) is Signal.SIGTERM
+    assert Signal.strict(Signal.SIGTERM) is Signal.SIGTERM
+    with pytest.raises(TypeError):
+        Signal.strict("15")
+    with pytest.raises(ValueError, match="0 is no signal"):
+        Signal.strict(0)
+
+
+def test_from_index():
+    assert Signal.from_index(15) is Signal.SIGTERM
+    with pytest.raises(IndexError):
+        Signal.from_index(0)
+
+
+def test_from_c_int():
+    assert Signal.from_c_int(15) is Signal.SIGTERM
+    with pytest.raises(ValueError):
+        Signal.from_c_int(0)
+
+
+def test_bytes():
+    assert bytes(Signal.SIGTERM) == b"\x0f"
+    assert Signal.from_bytes(b"\x0f") is Signal.SIGTERM
+    with pytest.raises(ValueError):
+        Signal.from_bytes(b"")
+
+
+def test_stdlib():
+    assert Signal.from_stdlib(signal.SIGTERM) is Signal.SIGTERM
+    assert Signal.SIGTERM.to_stdlib() is signal.SIGTERM
+    assert signal.Signals(Signal.SIGTERM) is signal.SIGTERM
+    for sig in Signal.all():
+        assert Signal.from_stdlib(sig.to_stdlib()) is sig
+
+
+def test_mask():
+    assert Signal.from_mask(0b110) == [Signal.SIGINT, Signal(3)]
+    assert Signal.to_mask([Signal.SIGINT, 3]) == 0b110
+    assert Signal.from_mask(Signal.to_mask(Signal.all())) == Signal.all()
+
+
+def test_match():
+    match Signal.SIGTERM:
+        case Signal(value=15):
+            pass
+        case _:
+            pytest.fail("no match")
+
+
+def test_class_getitem():
+    assert Signal[int] is Signal
+
+
+def test_final():
+    with pytest.raises(TypeError):
+
+        class Subclass(Signal):  # type: ignore[misc]
+            pass
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no SIGCONT")
+def test_os_kill():
+    received = []
+    previous = signal.signal(signal.SIGCONT, lambda signum, frame: received.append(signum))
+    try:
+        os.kill(os.getpid(), Signal.SIGCONT)
+    finally:
+        signal.signal(signal.SIGCONT, previous)
+    assert received == [signal.SIGCONT]
+
+
+# in a new interpreter, where e.g. `Signal.rtmin_plus(1)` is not cached yet
+FILL_CACHE = """
+import threading
+import pdeathsignal
+from pdeathsignal import Signal
+
+def signals():
+    barrier.wait()
+    for number in range(1, pdeathsignal.NSIG):
+        try:
+            yield number, Signal(number)
+        except ValueError:
+            pass
+
+barrier = threading.Barrier(8)
+results = [None] * 8
+def fill(index):
+    results[index] = dict(signals())
+threads = [threading.Thread(target=fill, args=(index,)) for index in range(8)]
+for thread in threads:
+    thread.start()
+for thread in threads:
+    thread.join()
+for result in results:
+    assert result.keys() == results[0].keys()
+    assert all(result[number] is results[0][number] for number in result)
+"""
+
+
+def test_cache_filled_by_threads():
+    subprocess.run([sys.executable, "-c", FILL_CACHE], check=True)
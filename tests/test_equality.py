@@ -0,0 +1,72 @@
+"""Equality, hashing and ordering of `Signal`"""
+
+import sys
+
+import pytest
+
+from pdeathsignal import Signal
+
+
+def test_equal_to_signal_and_number():
+    assert Signal.SIGTERM == Signal.SIGTERM
+    assert Signal.SIGTERM == 15
+    assert 15 == Signal.SIGTERM
+    assert Signal.SIGTERM != Signal.SIGINT
+    assert Signal.SIGTERM != 2
+
+
+def test_not_equal_to_other_types():
+    assert Signal.SIGTERM != 15.0
+    assert Signal.SIGTERM != None  # noqa: E711
+    assert Signal.SIGTERM != b"\x0f"
+
+
+def test_not_equal_to_mnemonic():
+    assert Signal.SIGTERM != "SIGTERM"
+    assert Signal.SIGTERM != "TERM"
+    assert Signal.SIGTERM not in {"TERM", "SIGTERM"}
+    assert Signal.from_name("TERM") == Signal.SIGTERM
+
+
+def test_hash_is_the_number():
+    assert hash(Signal.SIGTERM) == hash(15)
+    assert {Signal.SIGTERM: "term"}[15] == "term"
+    assert {15: "term"}[Signal.SIGTERM] == "term"
+    assert Signal.SIGTERM in {15}
+
+
+def test_equal_objects_have_equal_hashes():
+    for signal in Signal.all():
+        for other in (signal, int(signal), signal.name, signal.abbrev):
+            if signal == other:
+                assert hash(signal) == hash(other)
+
+
+def test_ordering():
+    assert Signal.SIGINT < Signal.SIGTERM
+    assert Signal.SIGINT <= Signal.SIGINT
+    assert Signal.SIGTERM > 2
+    assert Signal.SIGTERM >= 15
+    assert sorted([Signal.SIGTERM, Signal.SIGINT, Signal.SIGHUP]) == [1, 2, 15]
+
+
+@pytest.mark.skipif(not hasattr(Signal, "SIGRTMIN"), reason="no real-time signals")
+def test_real_time_signals_sort_last():
+    assert max(Signal.all()) < Signal.SIGRTMIN < Signal.SIGRTMAX
+
+
+@pytest.mark.parametrize("other", ["TERM", 15.0, None])
+def test_ordering_with_other_types_raises(other):
+    with pytest.raises(TypeError):
+        _ = Signal.SIGINT < other
+
+
+def test_bool_is_always_true():
+    assert all(bool(signal) for signal in Signal.all())
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no SIGKILL")
+def test_singletons():
+    assert Signal(9) is Signal.SIGKILL
+    assert Signal(Signal.SIGKILL) is Signal.SIGKILL
+    assert Signal("KILL") is Signal.SIGKILL
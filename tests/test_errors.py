@@ -0,0 +1,53 @@
+"""Errors reported by the OS are `OSError`s with a numeric errno"""
+
+import errno
+import os
+import sys
+
+import pytest
+
+import pdeathsignal
+
+pytestmark = pytest.mark.skipif(sys.platform == "win32", reason="no getsid()")
+
+
+def unused_pid():
+    pid = 2**22 - 1
+    while pid > 1:
+        try:
+            os.kill(pid, 0)
+        except ProcessLookupError:
+            return pid
+        except PermissionError:
+            pass
+        pid -= 1
+    pytest.skip("no unused PID")
+
+
+@pytest.mark.parametrize("function", [pdeathsignal.getsid, pdeathsignal.getpgid])
+def test_errno(function):
+    with pytest.raises(ProcessLookupError) as exc_info:
+        function(unused_pid())
+    err = exc_info.value
+    assert isinstance(err, OSError)
+    assert err.errno == errno.ESRCH
+    assert err.args[0] == errno.ESRCH
+    assert err.strerror == os.strerror(errno.ESRCH)
+    assert "os error" not in str(err)
+
+
+def test_try_set_reports_success():
+    if not pdeathsignal.supported():
+        pytest.skip("not supported")
+    assert pdeathsignal.try_set("TERM") == (True, 0)
+    assert pdeathsignal.try_set(None) == (True, 0)
+
+
+def test_unsupported_platform():
+    if pdeathsignal.supported():
+        pytest.skip("supported")
+    with pytest.raises(NotImplementedError):
+        pdeathsignal.set("TERM")
+    with pytest.raises(NotImplementedError):
+        pdeathsignal.get()
+    assert not pdeathsignal.can_set("TERM")
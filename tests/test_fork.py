@@ -0,0 +1,40 @@
+"""`keep_across_fork()` sets the signal again in forked children"""
+
+import os
+import sys
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(
+    sys.platform == "win32" or not pdeathsignal.supported(), reason="not supported"
+)
+
+
+def signal_in_child():
+    """The parent-death signal number of a forked child, or 0"""
+    pid = os.fork()
+    if pid == 0:
+        os._exit(pdeathsignal.get_raw() or 0)
+    _, status = os.waitpid(pid, 0)
+    return os.waitstatus_to_exitcode(status)
+
+
+def test_cleared_by_fork():
+    pdeathsignal.set(Signal.SIGTERM)
+    assert signal_in_child() == 0
+
+
+def test_keep_across_fork():
+    hook = pdeathsignal.keep_across_fork(Signal.SIGTERM)
+    assert signal_in_child() == Signal.SIGTERM
+    hook.unregister()
+    assert signal_in_child() == 0
+
+
+def test_disabled_by_reset():
+    pdeathsignal.keep_across_fork(Signal.SIGTERM)
+    pdeathsignal.reset()
+    assert signal_in_child() == 0
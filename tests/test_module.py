@@ -0,0 +1,45 @@
+"""Module-level names"""
+
+import importlib.metadata
+import sys
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+
+def test_all():
+    assert "Signal" in pdeathsignal.__all__
+    assert "__version__" not in pdeathsignal.__all__
+    for name in pdeathsignal.__all__:
+        assert hasattr(pdeathsignal, name)
+
+
+@pytest.mark.parametrize("name", ["Scoped", "Guard", "ParentDeathHandler", "ForkHook"])
+def test_handles_are_not_exported(name):
+    assert name not in pdeathsignal.__all__
+    assert isinstance(getattr(pdeathsignal, name), type)
+
+
+def test_version():
+    assert pdeathsignal.__version__ == importlib.metadata.version("pdeathsignal")
+
+
+def test_constants():
+    assert pdeathsignal.SIGTERM is Signal.SIGTERM
+    for name in dir(pdeathsignal):
+        if name.startswith("SIG"):
+            assert getattr(pdeathsignal, name) is getattr(Signal, name)
+
+
+@pytest.mark.skipif(not sys.platform.startswith("linux"), reason="Linux only")
+def test_prctl_options():
+    assert pdeathsignal.PR_SET_PDEATHSIG == 1
+    assert pdeathsignal.PR_GET_PDEATHSIG == 2
+
+
+def test_supported():
+    assert pdeathsignal.supported() == (
+        sys.platform.startswith("linux") or sys.platform.startswith("freebsd")
+    )
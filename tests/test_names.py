@@ -0,0 +1,91 @@
+"""Mnemonics, parsing and formatting of `Signal`"""
+
+import sys
+
+import pytest
+
+from pdeathsignal import Signal
+
+
+def test_name():
+    assert Signal.SIGTERM.name == "SIGTERM"
+    assert Signal.SIGTERM.abbrev == "TERM"
+    assert str(Signal.SIGTERM) == "SIGTERM"
+
+
+def test_repr():
+    assert repr(Signal.SIGTERM) == "pdeathsignal.Signal.SIGTERM"
+    assert Signal.SIGTERM.compact_repr() == "SIGTERM"
+
+
+@pytest.mark.parametrize("name", ["SIGTERM", "TERM", "term", "sigterm"])
+def test_from_name(name):
+    assert Signal.from_name(name) is Signal.SIGTERM
+
+
+def test_from_name_unknown():
+    with pytest.raises(ValueError, match="Unknown signal name"):
+        Signal.from_name("NOPE")
+
+
+@pytest.mark.parametrize("value", [Signal.SIGTERM, 15, "TERM", "SIGTERM", " 15 ", " TERM\n"])
+def test_parse(value):
+    assert Signal.parse(value) is Signal.SIGTERM
+    assert Signal(value) is Signal.SIGTERM
+
+
+@pytest.mark.parametrize("value", ["15", " 15 "])
+def test_numeric_strings(value):
+    assert Signal.parse(value) is Signal.SIGTERM
+    assert Signal(value) is Signal.SIGTERM
+
+
+@pytest.mark.parametrize("value", [0, -1, 99999, "0", "NOPE"])
+def test_parse_invalid(value):
+    with pytest.raises(ValueError):
+        Signal(value)
+
+
+def test_format():
+    assert f"{Signal.SIGTERM}" == "SIGTERM"
+    assert f"{Signal.SIGTERM:d}" == "15"
+    assert f"{Signal.SIGTERM:x}" == "f"
+    assert f"{Signal.SIGTERM:>8}" == " SIGTERM"
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no historical aliases")
+def test_aliases():
+    assert Signal.SIGIOT is Signal.SIGABRT
+    assert Signal.from_name("IOT") is Signal.SIGABRT
+
+
+@pytest.mark.skipif(not sys.platform.startswith("linux"), reason="Linux aliases")
+def test_linux_aliases():
+    assert Signal.SIGCLD is Signal.SIGCHLD
+    assert Signal.SIGPOLL is Signal.SIGIO
+    assert Signal.SIGUNUSED is Signal.SIGSYS
+
+
+def test_description():
+    assert Signal.SIGTERM.description
+
+
+def test_doc():
+    assert Signal.SIGTERM.__doc__.startswith("SIGTERM (15): ")
+    assert Signal.__doc__.startswith("A signal number")
+
+
+def test_default_action():
+    assert Signal.SIGTERM.default_action == "term"
+    assert Signal.SIGSEGV.default_action == "core"
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no POSIX signals")
+def test_posix_default_action():
+    assert Signal.SIGCHLD.default_action == "ignore"
+    assert Signal.SIGCONT.default_action == "cont"
+    assert Signal.SIGTSTP.default_action == "stop"
+    assert Signal.SIGTSTP.is_stop
+    assert not Signal.SIGKILL.is_catchable
+    assert not Signal.SIGSTOP.is_catchable
+    assert Signal.SIGTERM.is_catchable
@@ -0,0 +1,63 @@
+"""`owning_tid()`: the thread that set the parent-death signal"""
+
+import sys
+import threading
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(not sys.platform.startswith("linux"), reason="Linux only")
+
+
+def run_in_thread(function):
+    result = []
+    thread = threading.Thread(target=lambda: result.append(function()))
+    thread.start()
+    thread.join()
+    return result[0]
+
+
+def set_in_thread(signal):
+    def function():
+        pdeathsignal.set(signal)
+        return threading.get_native_id()
+
+    return run_in_thread(function)
+
+
+def test_unset():
+    assert pdeathsignal.owning_tid() is None
+
+
+def test_set_and_clear():
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.owning_tid() == threading.get_native_id()
+    pdeathsignal.clear()
+    assert pdeathsignal.owning_tid() is None
+
+
+def test_worker_thread():
+    worker = set_in_thread(Signal.SIGTERM)
+    assert pdeathsignal.owning_tid() == worker
+
+
+def test_clear_in_other_thread_keeps_owner():
+    worker = set_in_thread(Signal.SIGTERM)
+    pdeathsignal.clear()
+    assert pdeathsignal.owning_tid() == worker
+
+
+def test_most_recent_owner():
+    worker = set_in_thread(Signal.SIGTERM)
+    pdeathsignal.set(Signal.SIGINT)
+    assert pdeathsignal.owning_tid() == threading.get_native_id()
+    pdeathsignal.clear()
+    assert pdeathsignal.owning_tid() == worker
+
+
+def test_reset():
+    set_in_thread(Signal.SIGTERM)
+    pdeathsignal.reset()
+    assert pdeathsignal.owning_tid() is None
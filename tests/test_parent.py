@@ -0,0 +1,179 @@
+"""Helpers that watch the parent process"""
+
+import asyncio
+import os
+import selectors
+import signal
+import sys
+import time
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(sys.platform == "win32", reason="POSIX only")
+
+requires_pidfd = pytest.mark.skipif(not sys.platform.startswith("linux"), reason="no pidfd")
+
+
+@pytest.fixture
+def received():
+    received = []
+    previous = signal.signal(signal.SIGUSR1, lambda signum, frame: received.append(signum))
+    yield received
+    signal.signal(signal.SIGUSR1, previous)
+
+
+def in_orphan(function):
+    """Run `function(ready)` in a grandchild, and return what it returned as a string
+
+    The grandchild's parent exits shortly after the grandchild called `ready()`.
+    """
+    result_r, result_w = os.pipe()
+    ready_r, ready_w = os.pipe()
+    child = os.fork()
+    if child == 0:
+        try:
+            if os.fork() == 0:
+                try:
+                    result = function(lambda: os.write(ready_w, b"."))
+                    os.write(result_w, str(result).encode())
+                finally:
+                    os._exit(0)
+            os.read(ready_r, 1)
+            # give `function` time to start waiting, if it could not call `ready()` afterwards
+            time.sleep(0.2)
+        finally:
+            os._exit(0)
+    for fd in (result_w, ready_r, ready_w):
+        os.close(fd)
+    os.waitpid(child, 0)
+    with os.fdopen(result_r, "rb") as reader:
+        return reader.read().decode()
+
+
+def test_getppid():
+    assert pdeathsignal.getppid() == os.getppid()
+    assert pdeathsignal.parent_is_alive(os.getppid())
+    assert not pdeathsignal.parent_is_alive(os.getpid())
+
+
+def test_getsid_getpgid():
+    assert pdeathsignal.getsid() == os.getsid(0)
+    assert pdeathsignal.getpgid() == os.getpgid(0)
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_set_if_orphaned(received):
+    assert not pdeathsignal.set_if_orphaned(Signal.SIGUSR1)
+    assert pdeathsignal.get() is Signal.SIGUSR1
+    assert received == []
+    assert pdeathsignal.set_if_orphaned(Signal.SIGUSR1, original_ppid=os.getpid())
+    assert received == [signal.SIGUSR1]
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_guard_orphaned(received):
+    with pdeathsignal.guard(Signal.SIGUSR1, original_ppid=os.getpid()):
+        assert pdeathsignal.get() is Signal.SIGUSR1
+    assert pdeathsignal.get() is None
+    assert received == [signal.SIGUSR1]
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_raise_configured(received):
+    assert pdeathsignal.raise_configured() is None
+    pdeathsignal.set(Signal.SIGUSR1)
+    assert pdeathsignal.raise_configured() is Signal.SIGUSR1
+    assert received == [signal.SIGUSR1]
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_on_parent_death():
+    handler = pdeathsignal.on_parent_death(lambda signum, frame: None, Signal.SIGUSR2)
+    assert handler.signal is Signal.SIGUSR2
+    assert pdeathsignal.get() is Signal.SIGUSR2
+    handler.remove()
+    assert pdeathsignal.get() is None
+    assert signal.getsignal(signal.SIGUSR2) == signal.SIG_DFL
+    handler.remove()
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_on_parent_death_fires():
+    def wait(ready):
+        fired = []
+        pdeathsignal.on_parent_death(lambda signum, frame: fired.append(signum), Signal.SIGUSR2)
+        ready()
+        os.kill(os.getppid(), signal.SIGKILL)
+        deadline = time.monotonic() + 5.0
+        while not fired and time.monotonic() < deadline:
+            time.sleep(0.01)
+        return fired == [signal.SIGUSR2]
+
+    assert in_orphan(wait) == "True"
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_self_test():
+    delivered, message = pdeathsignal.self_test(5.0)
+    assert delivered, message
+
+
+@requires_pidfd
+def test_wait_parent_exit_blocking():
+    assert not pdeathsignal.wait_parent_exit_blocking(0.01)
+
+    def wait(ready):
+        ready()
+        return pdeathsignal.wait_parent_exit_blocking(5.0)
+
+    assert in_orphan(wait) == "True"
+
+
+@requires_pidfd
+def test_wait_parent_exit_blocking_infinite():
+    def wait(ready):
+        ready()
+        return pdeathsignal.wait_parent_exit_blocking(float("inf"))
+
+    assert in_orphan(wait) == "True"
+
+
+@requires_pidfd
+def test_wait_parent_exit():
+    async def wait(ready):
+        future = pdeathsignal.wait_parent_exit()
+        ready()
+        await asyncio.wait_for(future, 5.0)
+        return "exited"
+
+    assert in_orphan(lambda ready: asyncio.run(wait(ready))) == "exited"
+
+
+@requires_pidfd
+def test_parent_death_fd():
+    fd = pdeathsignal.parent_death_fd()
+    try:
+        assert fd >= 0
+    finally:
+        os.close(fd)
+
+
+@requires_pidfd
+def test_parent_death_fd_with_selectors():
+    def wait(ready):
+        fd = pdeathsignal.parent_death_fd()
+        try:
+            with selectors.DefaultSelector() as selector:
+                selector.register(fd, selectors.EVENT_READ)
+                before = selector.select(0)
+                ready()
+                os.kill(os.getppid(), signal.SIGKILL)
+                after = selector.select(5.0)
+        finally:
+            os.close(fd)
+        return len(before), len(after)
+
+    assert in_orphan(wait) == "(0, 1)"
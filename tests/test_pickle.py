@@ -0,0 +1,45 @@
+"""Pickling and copying keep the singletons"""
+
+import copy
+import pickle
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+PROTOCOLS = range(pickle.HIGHEST_PROTOCOL + 1)
+
+
+@pytest.mark.parametrize("protocol", PROTOCOLS)
+def test_signal(protocol):
+    for signal in Signal.all():
+        assert pickle.loads(pickle.dumps(signal, protocol)) is signal
+
+
+@pytest.mark.parametrize("protocol", PROTOCOLS)
+@pytest.mark.skipif(not hasattr(Signal, "SIGRTMIN"), reason="no real-time signals")
+def test_real_time_signal(protocol):
+    signal = Signal.rtmin_plus(3)
+    assert pickle.loads(pickle.dumps(signal, protocol)) is signal
+
+
+@pytest.mark.parametrize("protocol", PROTOCOLS)
+def test_signal_set(protocol):
+    signals = pdeathsignal.SignalSet([Signal.SIGINT, Signal.SIGTERM])
+    assert pickle.loads(pickle.dumps(signals, protocol)) == signals
+
+
+@pytest.mark.parametrize("protocol", PROTOCOLS)
+def test_disabled(protocol):
+    assert pickle.loads(pickle.dumps(pdeathsignal.DISABLED, protocol)) is pdeathsignal.DISABLED
+
+
+def test_copy():
+    assert copy.copy(Signal.SIGTERM) is Signal.SIGTERM
+    assert copy.deepcopy(Signal.SIGTERM) is Signal.SIGTERM
+    assert copy.deepcopy([Signal.SIGTERM])[0] is Signal.SIGTERM
+
+
+def test_state():
+    assert Signal.SIGTERM.__getstate__() == 15
@@ -0,0 +1,67 @@
+"""`Policy`: a reusable list of fallback signals"""
+
+import warnings
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Policy, Signal
+
+pytestmark = pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+
+
+def test_keyword_arguments():
+    policy = Policy(signals=["TERM", Signal.SIGHUP], warn_noncatchable=False)
+    assert policy.signals == [Signal.SIGTERM, Signal.SIGHUP]
+    assert not policy.warn_noncatchable
+    assert Policy([Signal.SIGTERM]).warn_noncatchable
+
+
+def test_validated_on_creation():
+    with pytest.raises(ValueError):
+        Policy(["NOPE"])
+    with pytest.raises(ValueError):
+        Policy([])
+    with pytest.raises(TypeError):
+        Policy([True])
+
+
+def test_malformed_signals_are_skipped():
+    assert Policy([2.0, "TERM"]).signals == [Signal.SIGTERM]
+    assert Policy([None, "NOPE", Signal.SIGHUP]).signals == [Signal.SIGHUP]
+    with pytest.raises(ValueError, match="Illegal signal number 0"):
+        Policy([None])
+
+
+def test_str_is_rejected():
+    with pytest.raises(TypeError, match="not str"):
+        Policy("TERM")
+
+
+def test_apply():
+    assert Policy([Signal.SIGTERM, Signal.SIGHUP]).apply() is Signal.SIGTERM
+    assert pdeathsignal.get() is Signal.SIGTERM
+
+
+def test_warns_before_setting():
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        with pytest.raises(RuntimeWarning):
+            Policy([Signal.SIGKILL]).apply()
+    # same as `set()`: a warning turned into an error prevents the change
+    assert pdeathsignal.get() is None
+    with pytest.warns(RuntimeWarning, match="SIGKILL"):
+        Policy([Signal.SIGKILL]).apply()
+    assert pdeathsignal.get() is Signal.SIGKILL
+
+
+def test_no_warning():
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        Policy([Signal.SIGKILL], warn_noncatchable=False).apply()
+
+
+def test_repr():
+    assert repr(Policy([Signal.SIGTERM])) == (
+        "pdeathsignal.Policy([pdeathsignal.Signal.SIGTERM], warn_noncatchable=True)"
+    )
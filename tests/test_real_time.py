@@ -0,0 +1,85 @@
+"""Real-time signals SIGRTMIN..SIGRTMAX"""
+
+import signal
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(not hasattr(Signal, "SIGRTMIN"), reason="no real-time signals")
+
+
+def test_range():
+    assert Signal.SIGRTMIN == signal.SIGRTMIN
+    assert Signal.SIGRTMAX == signal.SIGRTMAX
+    assert Signal.rtmin_plus(0) is Signal.SIGRTMIN
+    assert Signal.rtmin_plus(3) == signal.SIGRTMIN + 3
+
+
+def test_nsig():
+    assert pdeathsignal.NSIG == signal.NSIG
+
+
+def test_arithmetic():
+    assert Signal.rtmin_plus(3) is Signal.SIGRTMIN + 3
+    assert Signal.SIGRTMIN + (Signal.SIGRTMAX - Signal.SIGRTMIN) is Signal.SIGRTMAX
+    with pytest.raises(ValueError):
+        Signal.SIGRTMAX + 1
+
+
+def test_names():
+    signal = Signal.rtmin_plus(3)
+    assert str(signal) == "SIGRTMIN+3"
+    assert signal.name == "SIGRTMIN+3"
+
+
+def test_repr():
+    signal = Signal.rtmin_plus(3)
+    assert repr(signal) == f"pdeathsignal.Signal({int(signal)})"
+
+
+def test_parse():
+    assert Signal("SIGRTMIN+3") is Signal.rtmin_plus(3)
+    assert Signal("SIGRTMIN+0") is Signal.SIGRTMIN
+    assert Signal("rtmin+03") is Signal.rtmin_plus(3)
+    last = int(Signal.SIGRTMAX) - int(Signal.SIGRTMIN)
+    assert Signal(f"SIGRTMIN+{last}") is Signal.SIGRTMAX
+    for name in [f"SIGRTMIN+{last + 1}", "SIGRTMIN+", "SIGRTMIN+-1", "SIGRTMIN++1"]:
+        with pytest.raises(ValueError):
+            Signal(name)
+
+
+def test_names_round_trip():
+    for offset in range(int(Signal.SIGRTMAX) - int(Signal.SIGRTMIN) + 1):
+        signal = Signal.rtmin_plus(offset)
+        assert Signal(signal.name) is signal
+        assert Signal(f"SIGRTMIN+{offset}") is signal
+
+
+def test_every_number():
+    for number in range(1, pdeathsignal.NSIG):
+        try:
+            signal = Signal(number)
+        except ValueError:
+            continue
+        assert int(signal) == number
+        assert Signal(number) is signal
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+@pytest.mark.parametrize("offset", [0, 3])
+def test_set(offset):
+    signal = Signal.rtmin_plus(offset)
+    pdeathsignal.set(signal)
+    assert pdeathsignal.get() is signal
+    assert pdeathsignal.get_raw() == int(signal)
+    assert pdeathsignal.is_set()
+    pdeathsignal.clear()
+    assert pdeathsignal.get() is None
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_set_max():
+    pdeathsignal.set(Signal.SIGRTMAX)
+    assert pdeathsignal.get() is Signal.SIGRTMAX
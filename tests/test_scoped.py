@@ -0,0 +1,76 @@
+"""Context managers that restore the previous setting"""
+
+import threading
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+
+
+def test_scoped():
+    pdeathsignal.set(Signal.SIGINT)
+    with pdeathsignal.scoped(Signal.SIGTERM):
+        assert pdeathsignal.get() is Signal.SIGTERM
+    assert pdeathsignal.get() is Signal.SIGINT
+
+
+def test_scoped_restores_after_exception():
+    with pytest.raises(KeyError):
+        with pdeathsignal.scoped(Signal.SIGTERM):
+            raise KeyError
+    assert pdeathsignal.get() is None
+
+
+def test_suspended():
+    pdeathsignal.set(Signal.SIGTERM)
+    with pdeathsignal.suspended():
+        assert pdeathsignal.get() is None
+    assert pdeathsignal.get() is Signal.SIGTERM
+
+
+def test_signal_as_context_manager():
+    with Signal.SIGTERM as signal:
+        assert signal is Signal.SIGTERM
+        assert pdeathsignal.get() is Signal.SIGTERM
+        with Signal.SIGINT:
+            assert pdeathsignal.get() is Signal.SIGINT
+        assert pdeathsignal.get() is Signal.SIGTERM
+    assert pdeathsignal.get() is None
+
+
+def test_signal_as_context_manager_per_thread():
+    # overlapping `with` blocks in two threads must each restore their own previous setting
+    entered = threading.Barrier(2)
+    left = threading.Barrier(2)
+    results = {}
+
+    def first():
+        pdeathsignal.set(Signal.SIGINT)
+        with Signal.SIGTERM:
+            entered.wait()
+            left.wait()
+        results["first"] = pdeathsignal.get()
+
+    def second():
+        entered.wait()
+        with Signal.SIGHUP:
+            pass
+        left.wait()
+        results["second"] = pdeathsignal.get()
+
+    threads = [threading.Thread(target=first), threading.Thread(target=second)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+    assert results == {"first": Signal.SIGINT, "second": None}
+
+
+def test_guard_parent_alive():
+    pdeathsignal.set(Signal.SIGINT)
+    with pdeathsignal.guard(Signal.SIGTERM):
+        assert pdeathsignal.get() is Signal.SIGTERM
+    assert pdeathsignal.get() is Signal.SIGINT
@@ -0,0 +1,203 @@
+"""Setting, getting and clearing the parent-death signal"""
+
+import threading
+import warnings
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import DISABLED, Signal
+
+pytestmark = pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+
+
+def test_unset():
+    assert pdeathsignal.get() is None
+
+
+@pytest.mark.parametrize("value", [Signal.SIGTERM, 15, "TERM", "SIGTERM", " SIGTERM "])
+def test_set(value):
+    pdeathsignal.set(value)
+    assert pdeathsignal.get() is Signal.SIGTERM
+
+
+def test_set_numeric_string():
+    pdeathsignal.set("15")
+    assert pdeathsignal.get() is Signal.SIGTERM
+
+
+def test_get_raw():
+    assert pdeathsignal.get_raw() is None
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.get_raw() == 15
+
+
+def test_is_set():
+    assert not pdeathsignal.is_set()
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.is_set()
+
+
+def test_current():
+    assert Signal.get() is None
+    assert Signal.current() is None
+    pdeathsignal.set(Signal.SIGTERM)
+    assert Signal.get() is Signal.SIGTERM
+    assert Signal.current() is Signal.SIGTERM
+
+
+@pytest.mark.parametrize("value", [None, 0, DISABLED])
+def test_set_nothing_clears(value):
+    pdeathsignal.set(Signal.SIGTERM)
+    pdeathsignal.set(value)
+    assert pdeathsignal.get() is None
+
+
+def test_clear():
+    Signal.SIGTERM.set()
+    pdeathsignal.clear()
+    assert pdeathsignal.get() is None
+
+
+@pytest.mark.parametrize("value", [True, 2.0, b"TERM"])
+def test_set_rejects_other_types(value):
+    with pytest.raises(TypeError):
+        pdeathsignal.set(value)
+    assert pdeathsignal.get() is None
+
+
+def test_set_and_get():
+    assert pdeathsignal.set_and_get(Signal.SIGTERM) is None
+    assert pdeathsignal.set_and_get("INT") is Signal.SIGTERM
+    assert pdeathsignal.set_and_get(None) is Signal.SIGINT
+    assert pdeathsignal.get() is None
+
+
+def test_try_set():
+    assert pdeathsignal.try_set("TERM") == (True, 0)
+    assert pdeathsignal.get() is Signal.SIGTERM
+    with pytest.raises(ValueError):
+        pdeathsignal.try_set("NOPE")
+
+
+def test_get_default():
+    assert pdeathsignal.get(default="TERM") is Signal.SIGTERM
+    assert pdeathsignal.get(default=DISABLED) is None
+    pdeathsignal.set(Signal.SIGINT)
+    assert pdeathsignal.get(default="TERM") is Signal.SIGINT
+
+
+def test_get_required():
+    with pytest.raises(LookupError):
+        pdeathsignal.get(required=True)
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.get(required=True) is Signal.SIGTERM
+
+
+def test_get_sentinel():
+    assert pdeathsignal.get(use_sentinel=True) is DISABLED
+    assert not DISABLED
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.get(use_sentinel=True) is Signal.SIGTERM
+
+
+def test_get_cached():
+    assert pdeathsignal.get(cached=True) is None
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.get(cached=True) is Signal.SIGTERM
+    pdeathsignal.clear()
+    assert pdeathsignal.get(cached=True) is None
+
+
+def test_require_main_thread():
+    errors = []
+
+    def worker():
+        try:
+            pdeathsignal.set(Signal.SIGTERM, require_main_thread=True)
+        except RuntimeError as err:
+            errors.append(err)
+
+    thread = threading.Thread(target=worker)
+    thread.start()
+    thread.join()
+    assert len(errors) == 1
+    pdeathsignal.set(Signal.SIGTERM, require_main_thread=True)
+
+
+def test_get_from_two_threads():
+    results = []
+
+    def worker():
+        # the signal is per thread on Linux
+        pdeathsignal.set(Signal.SIGTERM)
+        results.append(all(pdeathsignal.get() is Signal.SIGTERM for _ in range(10000)))
+
+    threads = [threading.Thread(target=worker, daemon=True) for _ in range(2)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        # best effort: a deadlock would hang the thread, but not the test
+        thread.join(30.0)
+        assert not thread.is_alive()
+    assert results == [True, True]
+
+
+def test_warns_for_noncatchable():
+    with pytest.warns(RuntimeWarning, match="SIGKILL"):
+        pdeathsignal.set(Signal.SIGKILL)
+    assert pdeathsignal.get() is Signal.SIGKILL
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        pdeathsignal.set(Signal.SIGKILL, quiet=True)
+        pdeathsignal.set(Signal.SIGTERM)
+
+
+def test_set_first_supported():
+    assert pdeathsignal.set_first_supported(["NOPE", 99999, "USR1"]) is Signal.SIGUSR1
+    assert pdeathsignal.get() is Signal.SIGUSR1
+    with pytest.raises(ValueError):
+        pdeathsignal.set_first_supported([])
+
+
+def test_set_first_supported_skips_malformed_signals():
+    assert pdeathsignal.set_first_supported([2.0, "TERM"]) is Signal.SIGTERM
+    assert pdeathsignal.set_first_supported([None, "HUP"]) is Signal.SIGHUP
+    with pytest.raises(TypeError, match="not float"):
+        pdeathsignal.set_first_supported([2.0])
+    with pytest.raises(ValueError, match="Illegal signal number 0"):
+        pdeathsignal.set_first_supported([None])
+
+
+def test_set_first_supported_rejects_str():
+    with pytest.raises(TypeError, match="not str"):
+        pdeathsignal.set_first_supported("TERM")
+    assert pdeathsignal.get() is None
+
+
+def test_set_from_env(monkeypatch):
+    monkeypatch.delenv("PDEATHSIG", raising=False)
+    assert not pdeathsignal.set_from_env()
+    monkeypatch.setenv("PDEATHSIG", "TERM")
+    assert pdeathsignal.set_from_env()
+    assert pdeathsignal.get() is Signal.SIGTERM
+    monkeypatch.setenv("MY_PDEATHSIG", "2")
+    assert pdeathsignal.set_from_env("MY_PDEATHSIG")
+    assert pdeathsignal.get() is Signal.SIGINT
+
+
+def test_can_set():
+    assert pdeathsignal.can_set(Signal.SIGTERM)
+    assert pdeathsignal.can_set(None)
+    assert not pdeathsignal.can_set("NOPE")
+    assert pdeathsignal.get() is None
+    pdeathsignal.set(Signal.SIGINT)
+    assert pdeathsignal.can_set(Signal.SIGTERM)
+    assert pdeathsignal.get() is Signal.SIGINT
+
+
+def test_reset():
+    pdeathsignal.set(Signal.SIGTERM)
+    assert pdeathsignal.reset() is Signal.SIGTERM
+    assert pdeathsignal.get() is None
+    assert pdeathsignal.reset() is None
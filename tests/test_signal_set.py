@@ -0,0 +1,74 @@
+"""`SignalSet` and the functions that list signals"""
+
+import sys
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal, SignalSet
+
+
+def test_set_operations():
+    signals = SignalSet([Signal.SIGINT, 15])
+    assert len(signals) == 2
+    assert Signal.SIGTERM in signals
+    assert 15 in signals
+    assert list(signals) == [Signal.SIGINT, Signal.SIGTERM]
+    assert list(signals | SignalSet([Signal.SIGSEGV])) == [2, 11, 15]
+    assert list(signals & SignalSet([15])) == [15]
+    assert list(signals - SignalSet([15])) == [2]
+    assert len(SignalSet()) == 0
+
+
+def test_hash():
+    assert SignalSet([2, 15]) == SignalSet([15, 2])
+    assert hash(SignalSet([2, 15])) == hash(SignalSet([15, 2]))
+    assert len({SignalSet([2, 15]), SignalSet([15, 2])}) == 1
+
+
+def test_catchable():
+    catchable = SignalSet.catchable()
+    assert Signal.SIGTERM in catchable
+    if sys.platform != "win32":
+        assert Signal.SIGKILL not in catchable
+        assert Signal.SIGSTOP not in catchable
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no POSIX signals")
+def test_groups():
+    assert set(Signal.JOB_CONTROL) == {
+        Signal.SIGCONT,
+        Signal.SIGSTOP,
+        Signal.SIGTSTP,
+        Signal.SIGTTIN,
+        Signal.SIGTTOU,
+    }
+    assert set(Signal.TERMINAL) == {Signal.SIGHUP, Signal.SIGINT, Signal.SIGQUIT, Signal.SIGWINCH}
+    assert all(signal.default_action == "core" for signal in Signal.CORE_DUMPING)
+
+
+def test_all():
+    signals = Signal.all()
+    assert signals == sorted(signals)
+    assert Signal.SIGTERM in signals
+
+
+def test_signals():
+    assert pdeathsignal.signals() == sorted(pdeathsignal.signals())
+    assert all(signal.is_catchable for signal in pdeathsignal.signals(catchable=True))
+    stop = pdeathsignal.signals(default_action="stop")
+    assert all(signal.default_action == "stop" for signal in stop)
+
+
+def test_recommended_signals():
+    recommended = pdeathsignal.recommended_signals()
+    assert Signal.SIGTERM in recommended
+    for signal in recommended:
+        assert signal.is_catchable
+        assert signal.default_action in ("term", "core")
+
+
+@pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+def test_blocked_signals():
+    assert Signal.SIGTERM not in pdeathsignal.blocked_signals()
+    assert not pdeathsignal.ensure_deliverable()
@@ -0,0 +1,76 @@
+"""`set(strict=True)` catches double initialization"""
+
+import os
+import sys
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+
+
+def test_strict_after_set():
+    pdeathsignal.set(Signal.SIGTERM, strict=True)
+    with pytest.raises(RuntimeError):
+        pdeathsignal.set(Signal.SIGINT, strict=True)
+    assert pdeathsignal.get() is Signal.SIGTERM
+
+
+@pytest.mark.parametrize(
+    "setter",
+    [
+        lambda: Signal.SIGTERM.set(),
+        lambda: pdeathsignal.try_set(Signal.SIGTERM),
+        lambda: pdeathsignal.set_and_get(Signal.SIGTERM),
+        lambda: pdeathsignal.set_first_supported([Signal.SIGTERM]),
+        lambda: pdeathsignal.Policy([Signal.SIGTERM]).apply(),
+        lambda: pdeathsignal.scoped(Signal.SIGTERM).__enter__(),
+        lambda: pdeathsignal.guard(Signal.SIGTERM).__enter__(),
+        lambda: Signal.SIGTERM.__enter__(),
+    ],
+)
+def test_strict_after_any_setter(setter):
+    setter()
+    with pytest.raises(RuntimeError):
+        pdeathsignal.set(Signal.SIGINT, strict=True)
+
+
+@pytest.mark.parametrize(
+    "clearer",
+    [
+        pdeathsignal.clear,
+        pdeathsignal.reset,
+        lambda: pdeathsignal.set(None),
+        lambda: pdeathsignal.set_and_get(None),
+        lambda: pdeathsignal.try_set(None),
+    ],
+)
+def test_strict_after_clear(clearer):
+    pdeathsignal.set(Signal.SIGTERM)
+    clearer()
+    pdeathsignal.set(Signal.SIGINT, strict=True)
+    assert pdeathsignal.get() is Signal.SIGINT
+
+
+def test_strict_after_scope():
+    with pdeathsignal.scoped(Signal.SIGTERM):
+        pass
+    pdeathsignal.set(Signal.SIGINT, strict=True)
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="no fork()")
+def test_strict_in_forked_child():
+    pdeathsignal.set(Signal.SIGTERM, strict=True)
+    pid = os.fork()
+    if pid == 0:
+        try:
+            pdeathsignal.set(Signal.SIGINT, strict=True)
+        except RuntimeError:
+            os._exit(1)
+        os._exit(0 if pdeathsignal.get() is Signal.SIGINT else 2)
+    _, status = os.waitpid(pid, 0)
+    assert os.waitstatus_to_exitcode(status) == 0
+    with pytest.raises(RuntimeError):
+        pdeathsignal.set(Signal.SIGINT, strict=True)
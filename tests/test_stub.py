@@ -0,0 +1,128 @@
+"""The type stub `__init__.pyi` must match the runtime module"""
+
+import ast
+import inspect
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+import pdeathsignal
+
+STUB = Path(pdeathsignal.__file__).with_suffix(".pyi")
+
+
+def declarations(body):
+    """Names declared in `body`, following `if sys.platform ...:` for the running platform"""
+    result = {}
+    for node in body:
+        if isinstance(node, ast.If):
+            taken = eval(compile(ast.Expression(node.test), STUB, "eval"), {"sys": sys})
+            result.update(declarations(node.body if taken else node.orelse))
+        elif isinstance(node, (ast.FunctionDef, ast.ClassDef)):
+            result.setdefault(node.name, []).append(node)
+        elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+            result.setdefault(node.target.id, []).append(node)
+    return result
+
+
+MODULE = declarations(ast.parse(STUB.read_text(), STUB).body)
+CLASSES = {name: nodes[0] for name, nodes in MODULE.items() if isinstance(nodes[0], ast.ClassDef)}
+FUNCTIONS = {
+    name: nodes for name, nodes in MODULE.items() if isinstance(nodes[0], ast.FunctionDef)
+}
+
+
+def parameters(node):
+    """`(name, kind)` of the parameters of a stub function"""
+    args = node.args
+    kind = inspect.Parameter
+    return [
+        *((a.arg, kind.POSITIONAL_ONLY) for a in args.posonlyargs),
+        *((a.arg, kind.POSITIONAL_OR_KEYWORD) for a in args.args),
+        *(((args.vararg.arg, kind.VAR_POSITIONAL),) if args.vararg else ()),
+        *((a.arg, kind.KEYWORD_ONLY) for a in args.kwonlyargs),
+        *(((args.kwarg.arg, kind.VAR_KEYWORD),) if args.kwarg else ()),
+    ]
+
+
+def exported(body):
+    """The stub's `__all__`, following `if sys.platform ...:` for the running platform"""
+    result = []
+    for node in body:
+        if isinstance(node, ast.If):
+            taken = eval(compile(ast.Expression(node.test), STUB, "eval"), {"sys": sys})
+            result += exported(node.body if taken else node.orelse)
+        elif isinstance(node, (ast.Assign, ast.AugAssign)):
+            targets = node.targets if isinstance(node, ast.Assign) else [node.target]
+            if any(isinstance(t, ast.Name) and t.id == "__all__" for t in targets):
+                result += ast.literal_eval(node.value)
+    return result
+
+
+def test_module_names():
+    public = {name for name in MODULE if not name.startswith("_")}
+    assert all(hasattr(pdeathsignal, name) for name in public)
+    assert set(exported(ast.parse(STUB.read_text(), STUB).body)) == set(pdeathsignal.__all__)
+
+
+@pytest.mark.parametrize("name", sorted(CLASSES))
+def test_class_members(name):
+    runtime = getattr(pdeathsignal, name)
+    members = declarations(CLASSES[name].body)
+    missing = {member for member in members if not hasattr(runtime, member)}
+    assert not missing
+    undeclared = {
+        member
+        for member in vars(runtime)
+        if not member.startswith("_") and member not in members
+    }
+    assert not undeclared
+
+
+@pytest.mark.parametrize(
+    "name",
+    sorted(
+        name
+        for name, node in CLASSES.items()
+        if {"__new__", "__init__"} & set(declarations(node.body))
+    ),
+)
+def test_constructor_signatures(name):
+    runtime = inspect.signature(getattr(pdeathsignal, name)).parameters.values()
+    runtime = [(parameter.name, parameter.kind) for parameter in runtime]
+    members = declarations(CLASSES[name].body)
+    (node,) = members.get("__new__", members.get("__init__"))
+    # without `cls` or `self`
+    assert parameters(node)[1:] == runtime
+
+
+@pytest.mark.parametrize("name", sorted(FUNCTIONS))
+def test_function_signatures(name):
+    runtime = inspect.signature(getattr(pdeathsignal, name)).parameters.values()
+    runtime = [(parameter.name, parameter.kind) for parameter in runtime]
+    stubs = [parameters(node) for node in FUNCTIONS[name]]
+    if len(stubs) == 1:
+        assert stubs[0] == runtime
+    else:
+        # every `@overload` accepts a subset of the parameters
+        for stub in stubs:
+            assert set(stub) <= set(runtime)
+
+
+def test_defaults_are_ellipsis_or_literals():
+    for node in ast.walk(ast.parse(STUB.read_text(), STUB)):
+        if isinstance(node, ast.arguments):
+            for default in [*node.defaults, *filter(None, node.kw_defaults)]:
+                assert isinstance(default, ast.Constant), ast.unparse(default)
+
+
+def test_stubtest():
+    pytest.importorskip("mypy")
+    result = subprocess.run(
+        [sys.executable, "-m", "mypy.stubtest", "pdeathsignal"],
+        capture_output=True,
+        text=True,
+    )
+    assert result.returncode == 0, result.stdout + result.stderr
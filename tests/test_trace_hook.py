@@ -0,0 +1,46 @@
+"""`set_trace_hook()` sees every change made through the module"""
+
+import pytest
+
+import pdeathsignal
+from pdeathsignal import Signal
+
+pytestmark = pytest.mark.skipif(not pdeathsignal.supported(), reason="not supported")
+
+
+@pytest.fixture
+def events():
+    events = []
+    pdeathsignal.set_trace_hook(lambda *args: events.append(args))
+    yield events
+    pdeathsignal.set_trace_hook(None)
+
+
+def test_set_and_clear(events):
+    pdeathsignal.set(Signal.SIGTERM)
+    pdeathsignal.clear()
+    assert events == [("set", None, Signal.SIGTERM), ("clear", Signal.SIGTERM, None)]
+
+
+def test_can_set_is_not_traced(events):
+    assert pdeathsignal.can_set(Signal.SIGTERM)
+    assert events == []
+
+
+def test_removed_by_reset(events):
+    pdeathsignal.reset()
+    pdeathsignal.set(Signal.SIGTERM)
+    assert events == []
+
+
+def test_exceptions_are_propagated():
+    def hook(*args):
+        raise KeyError(args)
+
+    pdeathsignal.set_trace_hook(hook)
+    try:
+        with pytest.raises(KeyError):
+            pdeathsignal.set(Signal.SIGTERM)
+    finally:
+        pdeathsignal.set_trace_hook(None)
+    assert pdeathsignal.get() is Signal.SIGTERM